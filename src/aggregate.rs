@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use crate::model::GkgRecord;
+use crate::search::{fips, themes};
+
+/// Accumulates per-facet value counts as records stream by, without
+/// buffering the records themselves — an overview (top actors, top
+/// locations, dominant themes) of a result set in a single pass.
+///
+/// Supported facet names: `themes` (v1 + v2 enhanced, human-readable via
+/// [`themes::canonicalize_theme`]), `country` (v1 + v2 enhanced locations,
+/// expanded via [`fips::country_name`]), `persons` (v1 + v2 enhanced),
+/// `organizations` (v1 + v2 enhanced), `source`, `count_type` (from v1 and
+/// v21 counts), and `tone` (bucketed into fixed ranges via
+/// [`tone_bucket`]). An unrecognized facet name accumulates no counts.
+#[derive(Debug, Default)]
+pub struct FacetAggregator {
+    facets: Vec<String>,
+    counts: HashMap<String, HashMap<String, u64>>,
+}
+
+impl FacetAggregator {
+    pub fn new(facets: &[String]) -> Self {
+        Self {
+            counts: facets.iter().map(|f| (f.clone(), HashMap::new())).collect(),
+            facets: facets.to_vec(),
+        }
+    }
+
+    /// Folds one record's facet values into the running counts.
+    pub fn add_record(&mut self, record: &GkgRecord) {
+        for facet in &self.facets {
+            let counts = self.counts.get_mut(facet).expect("facet registered in new()");
+            for value in facet_values(record, facet) {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Emits the facet-distribution JSON object, e.g.
+    /// `{ "themes": [{ "value": "ELECTION", "count": 1240 }, ...], "country": [{ "value": "United States", "count": 880 }, ...] }`,
+    /// each facet sorted descending by count (ties broken by value) and
+    /// capped to its `top_n` highest-count entries (0 = unlimited).
+    pub fn finish(&self, top_n: usize) -> serde_json::Value {
+        let mut out = serde_json::Map::new();
+        for facet in &self.facets {
+            let mut entries: Vec<(&String, &u64)> = self.counts[facet].iter().collect();
+            entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+            if top_n > 0 {
+                entries.truncate(top_n);
+            }
+            let values: Vec<serde_json::Value> = entries
+                .into_iter()
+                .map(|(value, count)| serde_json::json!({ "value": value, "count": count }))
+                .collect();
+            out.insert(facet.clone(), serde_json::Value::Array(values));
+        }
+        serde_json::Value::Object(out)
+    }
+}
+
+fn facet_values(record: &GkgRecord, facet: &str) -> Vec<String> {
+    match facet {
+        "themes" => record
+            .v1_themes
+            .iter()
+            .map(|t| themes::canonicalize_theme(t))
+            .chain(
+                record
+                    .v2_enhanced_themes
+                    .iter()
+                    .map(|t| themes::canonicalize_theme(&t.theme)),
+            )
+            .collect(),
+        "country" => record
+            .v1_locations
+            .iter()
+            .map(|l| &l.country_code)
+            .chain(record.v2_enhanced_locations.iter().map(|l| &l.country_code))
+            .filter(|code| !code.is_empty())
+            .map(|code| fips::country_name(code).unwrap_or(code).to_string())
+            .collect(),
+        "persons" => record
+            .v1_persons
+            .iter()
+            .cloned()
+            .chain(record.v2_enhanced_persons.iter().map(|p| p.name.clone()))
+            .collect(),
+        "organizations" => record
+            .v1_organizations
+            .iter()
+            .cloned()
+            .chain(record.v2_enhanced_organizations.iter().map(|o| o.name.clone()))
+            .collect(),
+        "source" => vec![record.source_common_name.clone()],
+        "count_type" => record
+            .v1_counts
+            .iter()
+            .map(|c| c.count_type.clone())
+            .chain(record.v21_counts.iter().map(|c| c.count_type.clone()))
+            .collect(),
+        "tone" => record
+            .tone
+            .as_ref()
+            .map(|t| vec![tone_bucket(t.tone).to_string()])
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+    .into_iter()
+    .filter(|v| !v.is_empty())
+    .collect()
+}
+
+/// Buckets a GDELT tone score (roughly -100..100, typically -10..10) into
+/// one of five fixed ranges for histogram display.
+fn tone_bucket(tone: f64) -> &'static str {
+    if tone <= -5.0 {
+        "very negative (<= -5)"
+    } else if tone <= -1.0 {
+        "negative (-5, -1]"
+    } else if tone < 1.0 {
+        "neutral (-1, 1)"
+    } else if tone < 5.0 {
+        "positive [1, 5)"
+    } else {
+        "very positive (>= 5)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_record(themes: Vec<&str>, persons: Vec<&str>, source: &str) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: source.into(),
+            document_identifier: "https://example.com/a".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec![],
+            v2_enhanced_themes: themes
+                .into_iter()
+                .map(|t| EnhancedTheme { theme: t.into(), char_offset: 0 })
+                .collect(),
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: persons.into_iter().map(String::from).collect(),
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn counts_values_across_records() {
+        let mut agg = FacetAggregator::new(&["themes".to_string(), "source".to_string()]);
+        agg.add_record(&make_record(vec!["ELECTION"], vec![], "a.com"));
+        agg.add_record(&make_record(vec!["ELECTION"], vec![], "b.com"));
+        agg.add_record(&make_record(vec!["TAX_FNCACT_PRESIDENT"], vec![], "a.com"));
+
+        let result = agg.finish(0);
+        assert_eq!(facet_count(&result, "source", "a.com"), Some(2));
+        assert_eq!(facet_count(&result, "source", "b.com"), Some(1));
+        assert_eq!(facet_count(&result, "themes", "ELECTION"), Some(2));
+    }
+
+    #[test]
+    fn finish_caps_to_top_n_by_count() {
+        let mut agg = FacetAggregator::new(&["persons".to_string()]);
+        agg.add_record(&make_record(vec![], vec!["alice", "bob", "carol"], "a.com"));
+        agg.add_record(&make_record(vec![], vec!["alice"], "a.com"));
+
+        let result = agg.finish(1);
+        let persons = result["persons"].as_array().unwrap();
+        assert_eq!(persons.len(), 1);
+        assert_eq!(facet_count(&result, "persons", "alice"), Some(2));
+    }
+
+    #[test]
+    fn unrecognized_facet_name_accumulates_nothing() {
+        let mut agg = FacetAggregator::new(&["bogus".to_string()]);
+        agg.add_record(&make_record(vec!["ELECTION"], vec!["alice"], "a.com"));
+        let result = agg.finish(0);
+        assert_eq!(result["bogus"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn themes_facet_combines_v1_and_v2_enhanced() {
+        let mut record = make_record(vec!["ELECTION"], vec![], "a.com");
+        record.v1_themes = vec!["LEADER".into()];
+        let mut agg = FacetAggregator::new(&["themes".to_string()]);
+        agg.add_record(&record);
+        let result = agg.finish(0);
+        assert_eq!(facet_count(&result, "themes", "ELECTION"), Some(1));
+        assert_eq!(facet_count(&result, "themes", "LEADER"), Some(1));
+    }
+
+    #[test]
+    fn count_type_facet_combines_v1_and_v21_counts() {
+        let mut record = make_record(vec![], vec![], "a.com");
+        record.v1_counts = vec![CountV1 {
+            count_type: "KILL".into(),
+            count: 3,
+            object_type: "people".into(),
+            location: LocationV1::default(),
+        }];
+        record.v21_counts = vec![CountV21 {
+            count_type: "KILL".into(),
+            count: 1,
+            object_type: "people".into(),
+            location: LocationV1::default(),
+            char_offset: 0,
+        }];
+        let mut agg = FacetAggregator::new(&["count_type".to_string()]);
+        agg.add_record(&record);
+        let result = agg.finish(0);
+        assert_eq!(facet_count(&result, "count_type", "KILL"), Some(2));
+    }
+
+    #[test]
+    fn tone_facet_buckets_into_fixed_ranges() {
+        let mut negative = make_record(vec![], vec![], "a.com");
+        negative.tone = Some(Tone { tone: -7.0, ..Tone::default() });
+        let mut neutral = make_record(vec![], vec![], "a.com");
+        neutral.tone = Some(Tone { tone: 0.2, ..Tone::default() });
+
+        let mut agg = FacetAggregator::new(&["tone".to_string()]);
+        agg.add_record(&negative);
+        agg.add_record(&neutral);
+        let result = agg.finish(0);
+        assert_eq!(facet_count(&result, "tone", "very negative (<= -5)"), Some(1));
+        assert_eq!(facet_count(&result, "tone", "neutral (-1, 1)"), Some(1));
+    }
+
+    #[test]
+    fn tone_facet_skips_records_with_no_tone() {
+        let mut agg = FacetAggregator::new(&["tone".to_string()]);
+        agg.add_record(&make_record(vec![], vec![], "a.com"));
+        let result = agg.finish(0);
+        assert_eq!(result["tone"].as_array().unwrap().len(), 0);
+    }
+
+    /// Looks up the `count` for `value` within a facet's `Vec<{value, count}>`
+    /// array, as emitted by [`FacetAggregator::finish`].
+    fn facet_count(result: &serde_json::Value, facet: &str, value: &str) -> Option<u64> {
+        result[facet].as_array()?.iter().find(|entry| entry["value"] == value)?["count"].as_u64()
+    }
+}
@@ -15,6 +15,10 @@ pub struct Cli {
     /// Suppress non-error output
     #[arg(short, long, global = true)]
     pub quiet: bool,
+
+    /// Disable TLS certificate validation (opt-in; never enabled by default)
+    #[arg(long, global = true)]
+    pub insecure: bool,
 }
 
 #[derive(Subcommand)]
@@ -33,6 +37,9 @@ pub enum Command {
 
     /// NL search + analyze GKG records
     Analyze(AnalyzeArgs),
+
+    /// Continuously poll for new GKG updates and ingest them incrementally
+    Watch(WatchArgs),
 }
 
 #[derive(Args)]
@@ -56,12 +63,56 @@ pub struct FetchArgs {
     /// Keep the .zip file after extraction
     #[arg(long)]
     pub keep_zip: bool,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+
+    #[command(flatten)]
+    pub range: RangeArgs,
+}
+
+#[derive(Args)]
+pub struct RangeArgs {
+    /// Start of a historical date range (YYYYMMDDHHMMSS); enumerates every
+    /// 15-minute GDELT slice through `--to` instead of fetching one file
+    #[arg(long, requires = "to")]
+    pub from: Option<String>,
+
+    /// End of a historical date range (YYYYMMDDHHMMSS); used with `--from`
+    #[arg(long, requires = "from")]
+    pub to: Option<String>,
+
+    /// Maximum number of slices to download concurrently
+    #[arg(long, default_value_t = 4)]
+    pub max_concurrency: usize,
+}
+
+#[derive(Args)]
+pub struct CacheArgs {
+    /// Bypass the persisted download cache and always fetch from GDELT
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Treat cached downloads older than this many minutes as stale
+    #[arg(long)]
+    pub cache_ttl: Option<u64>,
+
+    /// Directory for the persisted download/HTTP caches (default: persisted-storage/)
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
 }
 
 #[derive(Args)]
 pub struct ParseArgs {
-    /// Path to a local .csv or .csv.zip GKG file
-    pub file: PathBuf,
+    /// Path to a local .csv or .csv.zip GKG file (required unless --dir is given)
+    #[arg(required_unless_present = "dir")]
+    pub file: Option<PathBuf>,
+
+    /// Path to a directory of GKG CSVs/zips (recursively discovered, sorted)
+    /// or a multi-entry .csv.zip archive, streamed one record at a time
+    /// without collecting every entry into memory first
+    #[arg(long, conflicts_with = "file")]
+    pub dir: Option<PathBuf>,
 
     #[command(flatten)]
     pub filter: FilterArgs,
@@ -88,6 +139,18 @@ pub struct QueryArgs {
     #[arg(long)]
     pub persist_data_file: bool,
 
+    /// Fill in a record's translation_info by language-detecting its text
+    /// when GDELT didn't already supply one, requiring at least this
+    /// detector confidence in [0.0, 1.0] (e.g. 0.5). Off by default.
+    #[arg(long)]
+    pub backfill_translation: Option<f32>,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+
+    #[command(flatten)]
+    pub range: RangeArgs,
+
     #[command(flatten)]
     pub filter: FilterArgs,
 
@@ -113,6 +176,11 @@ pub struct FilterArgs {
     #[arg(long)]
     pub location: Option<String>,
 
+    /// Require exact accent/diacritic matching for --person, --org, and
+    /// --location (by default these fold diacritics, e.g. "jose" matches "José")
+    #[arg(long)]
+    pub exact_accents: bool,
+
     /// Filter by country FIPS code
     #[arg(long)]
     pub country: Option<String>,
@@ -144,6 +212,45 @@ pub struct FilterArgs {
     /// Only records with quotations
     #[arg(long)]
     pub has_quote: bool,
+
+    /// Boolean filter expression (e.g. "person = trump AND (tone < 0 OR
+    /// country IN [US, UK])"), ANDed together with the other --filter flags
+    #[arg(long)]
+    pub filter_expr: Option<String>,
+
+    /// Path to a JSON file holding a predicate tree (see
+    /// `filter::predicates::Predicate`), ANDed together with the other
+    /// --filter flags. Lets a filter ship as data instead of CLI flags.
+    #[arg(long)]
+    pub filter_file: Option<PathBuf>,
+
+    /// Only records mentioning a date (v2.1 enhanced dates) on or after this
+    /// one (YYYYMMDD), overlapping the window rather than requiring an exact
+    /// day match — requires --mentioned-date-to
+    #[arg(long, requires = "mentioned_date_to")]
+    pub mentioned_date_from: Option<i64>,
+
+    /// Only records mentioning a date (v2.1 enhanced dates) on or before this
+    /// one (YYYYMMDD) — requires --mentioned-date-from
+    #[arg(long, requires = "mentioned_date_from")]
+    pub mentioned_date_to: Option<i64>,
+
+    /// Latitude of the center point for --geo-radius-km (requires --geo-lon)
+    #[arg(long, requires = "geo_lon")]
+    pub geo_lat: Option<f64>,
+
+    /// Longitude of the center point for --geo-radius-km (requires --geo-lat)
+    #[arg(long, requires = "geo_lat")]
+    pub geo_lon: Option<f64>,
+
+    /// Only records with a location within this many km of (--geo-lat, --geo-lon)
+    #[arg(long, requires = "geo_lat")]
+    pub geo_radius_km: Option<f64>,
+
+    /// Only records with a location inside this bounding box, given as
+    /// "top_lat,bottom_lat,left_lon,right_lon"
+    #[arg(long)]
+    pub geo_bbox: Option<String>,
 }
 
 #[derive(Args)]
@@ -167,14 +274,35 @@ pub struct OutputArgs {
     /// Comma-separated list of field names to include
     #[arg(long, value_delimiter = ',')]
     pub fields: Option<Vec<String>>,
+
+    /// With --format facets, number of top entries per facet (0 = unlimited)
+    #[arg(long, default_value_t = 20)]
+    pub facet_limit: usize,
 }
 
 #[derive(Clone, ValueEnum)]
 pub enum OutputFormat {
     Json,
     JsonCompact,
+    /// Newline-delimited JSON (NDJSON): one compact object per line, no
+    /// surrounding array, for streaming into bulk-indexing endpoints
+    JsonLines,
     Tealeaf,
     TealeafCompact,
+    /// Length-delimited MessagePack, one frame per record
+    Msgpack,
+    /// Columnar Parquet file (buffers all records, written on completion)
+    Parquet,
+    /// Facet-count summary instead of individual records: --fields selects
+    /// which facets to tally (themes, country, persons, organizations,
+    /// source, count_type, tone)
+    Facets,
+    /// Self-contained HTML report (buffers all records, written on completion)
+    Html,
+    /// Normalized SQLite database (buffers all records, written on completion;
+    /// --output names the .sqlite file rather than stdout, since SQLite needs
+    /// a real path to open)
+    Sqlite,
 }
 
 #[derive(Args)]
@@ -209,6 +337,18 @@ pub struct AnalyzeArgs {
     #[arg(long)]
     pub persist_data_file: bool,
 
+    /// Fill in a record's translation_info by language-detecting its text
+    /// when GDELT didn't already supply one, requiring at least this
+    /// detector confidence in [0.0, 1.0] (e.g. 0.5). Off by default.
+    #[arg(long)]
+    pub backfill_translation: Option<f32>,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+
+    #[command(flatten)]
+    pub range: RangeArgs,
+
     /// Maximum number of results (default 20)
     #[arg(long, default_value_t = 20)]
     pub limit: usize,
@@ -221,6 +361,50 @@ pub struct AnalyzeArgs {
     #[arg(long, default_value_t = 10)]
     pub stats_top_n: usize,
 
+    /// Comma-separated facet field names to aggregate counts over instead of
+    /// returning individual records, e.g. "themes,country,persons"
+    #[arg(long, value_delimiter = ',')]
+    pub facets: Option<Vec<String>>,
+
+    /// Number of top entries per facet (0 = unlimited, default 20)
+    #[arg(long, default_value_t = 20)]
+    pub facet_limit: usize,
+
+    /// Relevance ranking mode
+    #[arg(long, default_value = "default", value_enum)]
+    pub ranking: RankingMode,
+
+    /// BM25 term-frequency saturation parameter (only used with --ranking bm25)
+    #[arg(long, default_value_t = 1.2)]
+    pub bm25_k1: f32,
+
+    /// BM25 document-length normalization parameter (only used with --ranking bm25)
+    #[arg(long, default_value_t = 0.75)]
+    pub bm25_b: f32,
+
+    /// Typo-tolerant fuzzy term matching (only used with --ranking bm25)
+    #[arg(long, default_value = "on", value_enum)]
+    pub typo_tolerance: TypoTolerance,
+
+    /// Per-field term-frequency multipliers for BM25 ranking (only used with
+    /// --ranking bm25), as a comma-separated "field=weight" list, e.g.
+    /// "themes=3.0,persons=1.5". Valid fields: source, persons,
+    /// organizations, themes, locations, quotations, names. Fields not
+    /// listed default to 1.0.
+    #[arg(long)]
+    pub bm25_field_weights: Option<String>,
+
+    /// Weight given to semantic (embedding) similarity vs keyword score, in
+    /// [0, 1]. 0 (default) disables hybrid scoring entirely.
+    #[arg(long, default_value_t = 0.0)]
+    pub semantic_ratio: f32,
+
+    /// HTTP endpoint for the embedding backend: POST {"input": text} ->
+    /// {"embedding": [f32, ...]}. Falls back to a deterministic, network-free
+    /// stub embedder when omitted (only used with --semantic-ratio > 0).
+    #[arg(long)]
+    pub embed_endpoint: Option<String>,
+
     #[command(flatten)]
     pub filter: FilterArgs,
 
@@ -228,6 +412,30 @@ pub struct AnalyzeArgs {
     pub output: AnalyzeOutputArgs,
 }
 
+#[derive(Clone, ValueEnum)]
+pub enum RankingMode {
+    /// The engine's built-in ranking (Tantivy's default scorer)
+    Default,
+    /// Explicit, tunable BM25 scoring via `--bm25-k1`/`--bm25-b`
+    Bm25,
+    /// Nearest-first ranking by distance instead of keyword relevance, using
+    /// the same --geo-lat/--geo-lon/--geo-radius-km or --geo-bbox flags that
+    /// otherwise only filter results. Requires one of those pairs to be set.
+    Geo,
+}
+
+#[derive(Clone, ValueEnum)]
+pub enum TypoTolerance {
+    Off,
+    On,
+}
+
+impl TypoTolerance {
+    pub fn is_on(&self) -> bool {
+        matches!(self, TypoTolerance::On)
+    }
+}
+
 #[derive(Args)]
 pub struct AnalyzeOutputArgs {
     /// Output format (defaults to tealeaf)
@@ -249,6 +457,35 @@ pub enum SchemaFormat {
     JsonSchema,
 }
 
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Poll interval in seconds (default 900, matching GDELT's 15-minute cadence)
+    #[arg(long, default_value_t = 900)]
+    pub interval_secs: u64,
+
+    /// Watch the translation (non-English) feed instead
+    #[arg(long)]
+    pub translation: bool,
+
+    /// Directory to download and extract GKG files into
+    #[arg(long, default_value = "./data")]
+    pub data_dir: PathBuf,
+
+    /// File used to persist the last-processed GKG file id across restarts
+    #[arg(long, default_value = ".newsfresh-watch-state")]
+    pub state_file: PathBuf,
+
+    /// Maximum consecutive retries on HTTP errors per poll cycle
+    #[arg(long, default_value_t = 5)]
+    pub max_retries: u32,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,6 +561,54 @@ mod tests {
         assert!(matches!(cli.command, Command::Schema(_)));
     }
 
+    #[test]
+    fn test_parse_fetch_with_range() {
+        let cli = Cli::try_parse_from([
+            "newsfresh",
+            "fetch",
+            "--from",
+            "20250217120000",
+            "--to",
+            "20250217123000",
+            "--max-concurrency",
+            "8",
+        ])
+        .unwrap();
+        if let Command::Fetch(args) = cli.command {
+            assert_eq!(args.range.from, Some("20250217120000".to_string()));
+            assert_eq!(args.range.to, Some("20250217123000".to_string()));
+            assert_eq!(args.range.max_concurrency, 8);
+        } else {
+            panic!("Expected Fetch");
+        }
+    }
+
+    #[test]
+    fn test_parse_fetch_range_requires_to() {
+        let result = Cli::try_parse_from(["newsfresh", "fetch", "--from", "20250217120000"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_analyze_with_semantic_ratio() {
+        let cli = Cli::try_parse_from([
+            "newsfresh",
+            "analyze",
+            "--search",
+            "unrest in the Sahel",
+            "--latest",
+            "--semantic-ratio",
+            "0.4",
+        ])
+        .unwrap();
+        if let Command::Analyze(args) = cli.command {
+            assert_eq!(args.semantic_ratio, 0.4);
+            assert!(args.embed_endpoint.is_none());
+        } else {
+            panic!("Expected Analyze");
+        }
+    }
+
     #[test]
     fn test_global_verbose_flag() {
         let cli = Cli::try_parse_from(["newsfresh", "-vv", "fetch"]).unwrap();
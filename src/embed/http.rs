@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::NewsfreshError;
+use crate::fetch::client::{self, ClientConfig};
+
+use super::Embedder;
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    input: &'a str,
+}
+
+#[derive(Deserialize)]
+struct EmbedResponse {
+    embedding: Vec<f32>,
+}
+
+/// Calls an HTTP/JSON embeddings endpoint: POSTs `{"input": text}` and
+/// expects `{"embedding": [f32, ...]}` back.
+///
+/// [`Embedder::embed`] is a synchronous trait method, but the HTTP client
+/// isn't, so each call runs to completion on a dedicated single-threaded
+/// Tokio runtime rather than requiring the whole embedding subsystem to be
+/// async.
+pub struct HttpEmbedder {
+    endpoint: String,
+    client_config: ClientConfig,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl HttpEmbedder {
+    pub fn new(endpoint: impl Into<String>, client_config: ClientConfig) -> Result<Self, NewsfreshError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| NewsfreshError::Other(format!("Failed to start embedder runtime: {e}")))?;
+        Ok(Self {
+            endpoint: endpoint.into(),
+            client_config,
+            runtime,
+        })
+    }
+}
+
+impl Embedder for HttpEmbedder {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, NewsfreshError> {
+        self.runtime.block_on(async {
+            let http_client = client::build_client(&self.client_config)?;
+            let resp = http_client
+                .post(&self.endpoint)
+                .json(&EmbedRequest { input: text })
+                .send()
+                .await?
+                .error_for_status()?;
+            let body: EmbedResponse = resp.json().await?;
+            Ok(body.embedding)
+        })
+    }
+}
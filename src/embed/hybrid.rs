@@ -0,0 +1,97 @@
+/// Cosine similarity between two equal-length vectors, mapped from `[-1, 1]`
+/// into `[0, 1]` via `(1 + cos) / 2` so it combines linearly with a
+/// normalized keyword score. Returns `0.0` for mismatched lengths, empty
+/// vectors, or zero-norm vectors rather than dividing by zero.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    let cos = (dot / (norm_a * norm_b)).clamp(-1.0, 1.0);
+    (1.0 + cos) / 2.0
+}
+
+/// Min-max normalizes `scores` into `[0, 1]`. When every score is equal (or
+/// there's only one), normalizes every entry to `1.0` rather than dividing
+/// by a zero range.
+pub fn normalize_minmax(scores: &[f32]) -> Vec<f32> {
+    if scores.is_empty() {
+        return Vec::new();
+    }
+    let min = scores.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = scores.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    if range <= f32::EPSILON {
+        return scores.iter().map(|_| 1.0).collect();
+    }
+    scores.iter().map(|&s| (s - min) / range).collect()
+}
+
+/// Combines a normalized keyword score with an optional semantic
+/// similarity: `ratio * semantic + (1 - ratio) * keyword`. Falls back to the
+/// pure keyword score when no semantic similarity is available (e.g. the
+/// record's embedding wasn't computed or the embedder call failed for it).
+pub fn combine_scores(keyword_score: f32, semantic_score: Option<f32>, ratio: f32) -> f32 {
+    match semantic_score {
+        Some(semantic) => ratio * semantic + (1.0 - ratio) * keyword_score,
+        None => keyword_score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_vectors_have_similarity_one() {
+        let v = vec![1.0, 2.0, 3.0];
+        assert!((cosine_similarity(&v, &v) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn opposite_vectors_have_similarity_zero() {
+        let a = vec![1.0, 0.0];
+        let b = vec![-1.0, 0.0];
+        assert!((cosine_similarity(&a, &b) - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn orthogonal_vectors_have_similarity_half() {
+        let a = vec![1.0, 0.0];
+        let b = vec![0.0, 1.0];
+        assert!((cosine_similarity(&a, &b) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mismatched_lengths_return_zero() {
+        assert_eq!(cosine_similarity(&[1.0, 2.0], &[1.0]), 0.0);
+    }
+
+    #[test]
+    fn normalize_minmax_spans_full_range() {
+        let scores = vec![1.0, 2.0, 4.0];
+        let normalized = normalize_minmax(&scores);
+        assert_eq!(normalized, vec![0.0, 1.0 / 3.0, 1.0]);
+    }
+
+    #[test]
+    fn normalize_minmax_all_equal_yields_ones() {
+        let scores = vec![5.0, 5.0, 5.0];
+        assert_eq!(normalize_minmax(&scores), vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn combine_scores_falls_back_to_keyword_without_semantic() {
+        assert_eq!(combine_scores(0.7, None, 0.5), 0.7);
+    }
+
+    #[test]
+    fn combine_scores_blends_by_ratio() {
+        assert!((combine_scores(0.0, Some(1.0), 0.25) - 0.25).abs() < 1e-6);
+    }
+}
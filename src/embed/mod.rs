@@ -0,0 +1,24 @@
+//! Dense embedding subsystem for hybrid semantic+keyword search.
+//!
+//! Turns the enriched text produced by [`search::enrich`](crate::search::enrich)
+//! into vectors via a pluggable [`Embedder`], stores them in a side
+//! [`EmbeddingStore`] keyed by `gkg_record_id` (never on `GkgRecord` itself,
+//! so output formats are unaffected), and combines cosine similarity with a
+//! normalized keyword score via [`combine_scores`].
+
+mod http;
+mod hybrid;
+mod stub;
+mod store;
+
+pub use http::HttpEmbedder;
+pub use hybrid::{combine_scores, cosine_similarity, normalize_minmax};
+pub use store::EmbeddingStore;
+pub use stub::StubEmbedder;
+
+use crate::error::NewsfreshError;
+
+/// Turns a blob of text into a dense vector representation.
+pub trait Embedder {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, NewsfreshError>;
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::model::GkgRecord;
+use crate::search::enrich;
+
+use super::Embedder;
+
+/// Embeddings for a batch of records, keyed by `gkg_record_id`. Built once
+/// via [`EmbeddingStore::build`] and kept separate from `GkgRecord` so
+/// embeddings never serialize into JSON/TeaLeaf/msgpack/Parquet output.
+#[derive(Default)]
+pub struct EmbeddingStore {
+    vectors: HashMap<String, Vec<f32>>,
+}
+
+impl EmbeddingStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Embeds the enriched text of every record, skipping (and counting) any
+    /// whose embedder call fails rather than aborting the whole batch.
+    /// Returns the number of records that failed to embed.
+    pub fn build(&mut self, records: &[GkgRecord], embedder: &dyn Embedder) -> usize {
+        let mut failures = 0;
+        for record in records {
+            let text = enriched_text_blob(record);
+            match embedder.embed(&text) {
+                Ok(vector) => {
+                    self.vectors.insert(record.gkg_record_id.clone(), vector);
+                }
+                Err(_) => failures += 1,
+            }
+        }
+        failures
+    }
+
+    pub fn get(&self, gkg_record_id: &str) -> Option<&[f32]> {
+        self.vectors.get(gkg_record_id).map(|v| v.as_slice())
+    }
+
+    pub fn contains(&self, gkg_record_id: &str) -> bool {
+        self.vectors.contains_key(gkg_record_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+}
+
+/// Concatenates the enriched text fields most indicative of topic/subject —
+/// persons, organizations, themes, locations, and quotations — into one blob
+/// suitable for embedding.
+pub fn enriched_text_blob(record: &GkgRecord) -> String {
+    let enriched = enrich::enrich_record(record);
+    [
+        enriched.persons,
+        enriched.organizations,
+        enriched.themes,
+        enriched.locations,
+        enriched.quotations,
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    struct FailingEmbedder;
+
+    impl Embedder for FailingEmbedder {
+        fn embed(&self, _text: &str) -> Result<Vec<f32>, crate::error::NewsfreshError> {
+            Err(crate::error::NewsfreshError::Other("embedder down".into()))
+        }
+    }
+
+    struct ConstantEmbedder;
+
+    impl Embedder for ConstantEmbedder {
+        fn embed(&self, _text: &str) -> Result<Vec<f32>, crate::error::NewsfreshError> {
+            Ok(vec![1.0, 0.0])
+        }
+    }
+
+    fn make_test_record(id: &str) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: id.into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec!["LEADER".into()],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec!["donald trump".into()],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn build_stores_one_vector_per_record() {
+        let records = vec![make_test_record("a"), make_test_record("b")];
+        let mut store = EmbeddingStore::new();
+        let failures = store.build(&records, &ConstantEmbedder);
+        assert_eq!(failures, 0);
+        assert_eq!(store.len(), 2);
+        assert!(store.contains("a"));
+    }
+
+    #[test]
+    fn build_counts_failures_and_skips_them() {
+        let records = vec![make_test_record("a")];
+        let mut store = EmbeddingStore::new();
+        let failures = store.build(&records, &FailingEmbedder);
+        assert_eq!(failures, 1);
+        assert!(store.is_empty());
+        assert!(store.get("a").is_none());
+    }
+
+    #[test]
+    fn enriched_text_blob_includes_persons() {
+        let record = make_test_record("a");
+        assert!(enriched_text_blob(&record).contains("donald trump"));
+    }
+}
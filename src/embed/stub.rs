@@ -0,0 +1,75 @@
+use crate::error::NewsfreshError;
+
+use super::Embedder;
+
+/// A deterministic, network-free embedder. Hashes the input text's bytes
+/// into a small fixed-size vector — not semantically meaningful, but useful
+/// for exercising the hybrid-scoring pipeline without a live embedding
+/// backend (tests, offline environments, `--semantic-ratio` without
+/// `--embed-endpoint`).
+pub struct StubEmbedder {
+    dimensions: usize,
+}
+
+impl StubEmbedder {
+    pub fn new(dimensions: usize) -> Self {
+        Self { dimensions }
+    }
+}
+
+impl Default for StubEmbedder {
+    fn default() -> Self {
+        Self::new(16)
+    }
+}
+
+impl Embedder for StubEmbedder {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, NewsfreshError> {
+        let mut vector = vec![0.0f32; self.dimensions];
+        for (i, byte) in text.bytes().enumerate() {
+            vector[i % self.dimensions] += byte as f32;
+        }
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for v in &mut vector {
+                *v /= norm;
+            }
+        }
+        Ok(vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embed_is_deterministic() {
+        let embedder = StubEmbedder::default();
+        let a = embedder.embed("Sahel unrest").unwrap();
+        let b = embedder.embed("Sahel unrest").unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn embed_differs_for_different_text() {
+        let embedder = StubEmbedder::default();
+        let a = embedder.embed("Sahel unrest").unwrap();
+        let b = embedder.embed("stock market rally").unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn embed_produces_requested_dimensions() {
+        let embedder = StubEmbedder::new(8);
+        let vector = embedder.embed("anything").unwrap();
+        assert_eq!(vector.len(), 8);
+    }
+
+    #[test]
+    fn embed_empty_text_yields_zero_vector() {
+        let embedder = StubEmbedder::default();
+        let vector = embedder.embed("").unwrap();
+        assert!(vector.iter().all(|&v| v == 0.0));
+    }
+}
@@ -17,9 +17,34 @@ pub enum NewsfreshError {
     #[error("Invalid date format: {0}")]
     InvalidDate(String),
 
+    #[error("Invalid filter expression: {0}")]
+    FilterExpression(String),
+
     #[error("No GKG file found in lastupdate response")]
     NoGkgFile,
 
+    #[error("Checksum mismatch for {path}: expected MD5 {expected}, got {actual}")]
+    ChecksumMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Size mismatch for {path}: expected {expected} bytes, got {actual} bytes")]
+    SizeMismatch {
+        path: String,
+        expected: u64,
+        actual: u64,
+    },
+
+    #[error("Integrity check failed for {path}: expected {algorithm} {expected}, got {actual}")]
+    Integrity {
+        path: String,
+        algorithm: &'static str,
+        expected: String,
+        actual: String,
+    },
+
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
@@ -27,6 +52,10 @@ pub enum NewsfreshError {
     #[error("Polars error: {0}")]
     Polars(#[from] polars::error::PolarsError),
 
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
     #[error("{0}")]
     Other(String),
 }
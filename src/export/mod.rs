@@ -0,0 +1,4 @@
+//! Exporters that write a parsed GKG slice out to formats meant for
+//! external tools rather than this crate's own search/output pipeline.
+
+pub mod sqlite;
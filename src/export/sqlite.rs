@@ -0,0 +1,429 @@
+use rusqlite::{params, Connection};
+
+use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoredRecord};
+use crate::output::OutputFormatter;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS gkg (
+    gkg_record_id TEXT PRIMARY KEY,
+    date INTEGER NOT NULL,
+    source_collection_id INTEGER NOT NULL,
+    source_common_name TEXT NOT NULL,
+    document_identifier TEXT NOT NULL,
+    sharing_image TEXT,
+    extras_xml TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_gkg_date ON gkg(date);
+
+CREATE TABLE IF NOT EXISTS themes (
+    gkg_record_id TEXT NOT NULL REFERENCES gkg(gkg_record_id),
+    theme TEXT NOT NULL,
+    char_offset INTEGER
+);
+CREATE INDEX IF NOT EXISTS idx_themes_record ON themes(gkg_record_id);
+CREATE INDEX IF NOT EXISTS idx_themes_theme ON themes(theme);
+
+CREATE TABLE IF NOT EXISTS locations (
+    gkg_record_id TEXT NOT NULL REFERENCES gkg(gkg_record_id),
+    location_type INTEGER NOT NULL,
+    full_name TEXT NOT NULL,
+    country_code TEXT NOT NULL,
+    adm1_code TEXT NOT NULL,
+    latitude REAL NOT NULL,
+    longitude REAL NOT NULL,
+    feature_id TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_locations_record ON locations(gkg_record_id);
+CREATE INDEX IF NOT EXISTS idx_locations_country ON locations(country_code);
+
+CREATE TABLE IF NOT EXISTS persons (
+    gkg_record_id TEXT NOT NULL REFERENCES gkg(gkg_record_id),
+    name TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_persons_record ON persons(gkg_record_id);
+
+CREATE TABLE IF NOT EXISTS organizations (
+    gkg_record_id TEXT NOT NULL REFERENCES gkg(gkg_record_id),
+    name TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_organizations_record ON organizations(gkg_record_id);
+
+CREATE TABLE IF NOT EXISTS counts (
+    gkg_record_id TEXT NOT NULL REFERENCES gkg(gkg_record_id),
+    count_type TEXT NOT NULL,
+    count INTEGER NOT NULL,
+    object_type TEXT NOT NULL,
+    country_code TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_counts_record ON counts(gkg_record_id);
+
+CREATE TABLE IF NOT EXISTS amounts (
+    gkg_record_id TEXT NOT NULL REFERENCES gkg(gkg_record_id),
+    amount REAL NOT NULL,
+    object TEXT NOT NULL,
+    char_offset INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_amounts_record ON amounts(gkg_record_id);
+
+CREATE TABLE IF NOT EXISTS tone (
+    gkg_record_id TEXT PRIMARY KEY REFERENCES gkg(gkg_record_id),
+    tone REAL NOT NULL,
+    positive_score REAL NOT NULL,
+    negative_score REAL NOT NULL,
+    polarity REAL NOT NULL,
+    activity_ref_density REAL NOT NULL,
+    self_group_ref_density REAL NOT NULL,
+    word_count INTEGER NOT NULL
+);
+";
+
+/// Writes `records` into a normalized SQLite database at `db_path`: a main
+/// `gkg` table keyed on `gkg_record_id`, plus one child table per repeating
+/// field (`themes`, `locations`, `persons`, `organizations`, `counts`,
+/// `amounts`, `tone`) joined back to it by that id, with indices on `date`,
+/// `country_code`, and `theme` for arbitrary SQL/joins over the slice.
+///
+/// All rows for all records are inserted in a single transaction, so a full
+/// 15-minute file streams straight to disk without buffering a second copy
+/// of the dataset in memory.
+///
+/// Re-exporting a record already present in `db_path` replaces it rather
+/// than erroring: the `gkg`/`tone` rows are upserted via `INSERT OR REPLACE`,
+/// and each child table's prior rows for that `gkg_record_id` are deleted
+/// before its new ones are inserted, so calling this twice against the same
+/// file (e.g. a repeated `--format sqlite` export) is idempotent.
+pub fn export_sqlite(records: &[GkgRecord], db_path: &str) -> Result<(), NewsfreshError> {
+    let mut conn = Connection::open(db_path)?;
+    conn.execute_batch(SCHEMA)?;
+
+    let tx = conn.transaction()?;
+    {
+        let mut insert_gkg = tx.prepare(
+            "INSERT OR REPLACE INTO gkg (gkg_record_id, date, source_collection_id, source_common_name, document_identifier, sharing_image, extras_xml) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )?;
+        let mut delete_themes = tx.prepare("DELETE FROM themes WHERE gkg_record_id = ?1")?;
+        let mut insert_theme =
+            tx.prepare("INSERT INTO themes (gkg_record_id, theme, char_offset) VALUES (?1, ?2, ?3)")?;
+        let mut delete_locations = tx.prepare("DELETE FROM locations WHERE gkg_record_id = ?1")?;
+        let mut insert_location = tx.prepare(
+            "INSERT INTO locations (gkg_record_id, location_type, full_name, country_code, adm1_code, latitude, longitude, feature_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        )?;
+        let mut delete_persons = tx.prepare("DELETE FROM persons WHERE gkg_record_id = ?1")?;
+        let mut insert_person =
+            tx.prepare("INSERT INTO persons (gkg_record_id, name) VALUES (?1, ?2)")?;
+        let mut delete_organizations = tx.prepare("DELETE FROM organizations WHERE gkg_record_id = ?1")?;
+        let mut insert_organization =
+            tx.prepare("INSERT INTO organizations (gkg_record_id, name) VALUES (?1, ?2)")?;
+        let mut delete_counts = tx.prepare("DELETE FROM counts WHERE gkg_record_id = ?1")?;
+        let mut insert_count = tx.prepare(
+            "INSERT INTO counts (gkg_record_id, count_type, count, object_type, country_code) VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        let mut delete_amounts = tx.prepare("DELETE FROM amounts WHERE gkg_record_id = ?1")?;
+        let mut insert_amount = tx.prepare(
+            "INSERT INTO amounts (gkg_record_id, amount, object, char_offset) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        let mut insert_tone = tx.prepare(
+            "INSERT OR REPLACE INTO tone (gkg_record_id, tone, positive_score, negative_score, polarity, activity_ref_density, self_group_ref_density, word_count) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        )?;
+
+        for record in records {
+            let id = record.gkg_record_id.as_str();
+            insert_gkg.execute(params![
+                id,
+                record.date,
+                record.source_collection_id,
+                record.source_common_name,
+                record.document_identifier,
+                record.sharing_image,
+                record.extras_xml,
+            ])?;
+
+            delete_themes.execute(params![id])?;
+            for theme in &record.v1_themes {
+                insert_theme.execute(params![id, theme, None::<i64>])?;
+            }
+            for theme in &record.v2_enhanced_themes {
+                insert_theme.execute(params![id, theme.theme, theme.char_offset])?;
+            }
+
+            delete_locations.execute(params![id])?;
+            for location in &record.v1_locations {
+                insert_location.execute(params![
+                    id,
+                    location.location_type,
+                    location.full_name,
+                    location.country_code,
+                    location.adm1_code,
+                    location.latitude,
+                    location.longitude,
+                    location.feature_id,
+                ])?;
+            }
+            for location in &record.v2_enhanced_locations {
+                insert_location.execute(params![
+                    id,
+                    location.location_type,
+                    location.full_name,
+                    location.country_code,
+                    location.adm1_code,
+                    location.latitude,
+                    location.longitude,
+                    location.feature_id,
+                ])?;
+            }
+
+            delete_persons.execute(params![id])?;
+            for person in &record.v1_persons {
+                insert_person.execute(params![id, person])?;
+            }
+            for person in &record.v2_enhanced_persons {
+                insert_person.execute(params![id, person.name])?;
+            }
+
+            delete_organizations.execute(params![id])?;
+            for organization in &record.v1_organizations {
+                insert_organization.execute(params![id, organization])?;
+            }
+            for organization in &record.v2_enhanced_organizations {
+                insert_organization.execute(params![id, organization.name])?;
+            }
+
+            delete_counts.execute(params![id])?;
+            for count in &record.v1_counts {
+                insert_count.execute(params![
+                    id,
+                    count.count_type,
+                    count.count,
+                    count.object_type,
+                    count.location.country_code,
+                ])?;
+            }
+            for count in &record.v21_counts {
+                insert_count.execute(params![
+                    id,
+                    count.count_type,
+                    count.count,
+                    count.object_type,
+                    count.location.country_code,
+                ])?;
+            }
+
+            delete_amounts.execute(params![id])?;
+            for amount in &record.amounts {
+                insert_amount.execute(params![id, amount.amount, amount.object, amount.char_offset])?;
+            }
+
+            if let Some(tone) = &record.tone {
+                insert_tone.execute(params![
+                    id,
+                    tone.tone,
+                    tone.positive_score,
+                    tone.negative_score,
+                    tone.polarity,
+                    tone.activity_ref_density,
+                    tone.self_group_ref_density,
+                    tone.word_count,
+                ])?;
+            }
+        }
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Buffers records in `write_record`/`write_scored_record` and flushes one
+/// [`export_sqlite`] database in `finish`, matching the `OutputFormatter`
+/// batch lifecycle [`super::super::output::parquet::ParquetFormatter`] uses
+/// rather than streaming row-by-row (SQLite needs a real file path to open,
+/// not an incremental `Write`, so this formatter ignores the usual writer
+/// and takes the destination path directly).
+pub struct SqliteFormatter {
+    db_path: String,
+    records: Vec<GkgRecord>,
+}
+
+impl SqliteFormatter {
+    pub fn new(db_path: String) -> Self {
+        Self {
+            db_path,
+            records: Vec::new(),
+        }
+    }
+}
+
+impl OutputFormatter for SqliteFormatter {
+    fn begin(&mut self) -> Result<(), NewsfreshError> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &GkgRecord) -> Result<(), NewsfreshError> {
+        self.records.push(record.clone());
+        Ok(())
+    }
+
+    fn write_scored_record(&mut self, scored: &ScoredRecord) -> Result<(), NewsfreshError> {
+        self.records.push(scored.record.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), NewsfreshError> {
+        export_sqlite(&self.records, &self.db_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_test_record() -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_counts: vec![CountV1 {
+                count_type: "KILL".into(),
+                count: 5,
+                object_type: "soldiers".into(),
+                location: LocationV1 {
+                    location_type: 1,
+                    full_name: "United States".into(),
+                    country_code: "US".into(),
+                    adm1_code: "US06".into(),
+                    latitude: 38.0,
+                    longitude: -97.0,
+                    feature_id: "US".into(),
+                },
+            }],
+            v21_counts: vec![],
+            v1_themes: vec!["LEADER".into()],
+            v2_enhanced_themes: vec![EnhancedTheme { theme: "ELECTION".into(), char_offset: 50 }],
+            v1_locations: vec![LocationV1 {
+                location_type: 1,
+                full_name: "United States".into(),
+                country_code: "US".into(),
+                adm1_code: "US06".into(),
+                latitude: 38.0,
+                longitude: -97.0,
+                feature_id: "US".into(),
+            }],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec!["donald trump".into()],
+            v2_enhanced_persons: vec![EnhancedEntity { name: "elon musk".into(), char_offset: 100 }],
+            v1_organizations: vec!["congress".into()],
+            v2_enhanced_organizations: vec![],
+            tone: Some(Tone {
+                tone: -1.5,
+                positive_score: 2.0,
+                negative_score: 3.5,
+                polarity: 5.5,
+                activity_ref_density: 10.0,
+                self_group_ref_density: 0.5,
+                word_count: 500,
+            }),
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: Some("https://img.example.com/photo.jpg".into()),
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![AmountEntry { amount: 42.0, object: "dollars".into(), char_offset: 10 }],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn export_sqlite_writes_the_main_record() {
+        let dir = std::env::temp_dir().join(format!("newsfresh-test-{}.sqlite", std::process::id()));
+        let path = dir.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        export_sqlite(&[make_test_record()], path).unwrap();
+
+        let conn = Connection::open(path).unwrap();
+        let id: String = conn
+            .query_row("SELECT gkg_record_id FROM gkg WHERE gkg_record_id = '1'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(id, "1");
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn export_sqlite_writes_child_rows_joined_by_record_id() {
+        let dir = std::env::temp_dir().join(format!("newsfresh-test-{}-child.sqlite", std::process::id()));
+        let path = dir.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        export_sqlite(&[make_test_record()], path).unwrap();
+
+        let conn = Connection::open(path).unwrap();
+        let theme_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM themes WHERE gkg_record_id = '1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(theme_count, 2);
+
+        let location_country: String = conn
+            .query_row(
+                "SELECT country_code FROM locations WHERE gkg_record_id = '1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(location_country, "US");
+
+        let tone: f64 =
+            conn.query_row("SELECT tone FROM tone WHERE gkg_record_id = '1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(tone, -1.5);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn export_sqlite_handles_records_without_tone() {
+        let dir = std::env::temp_dir().join(format!("newsfresh-test-{}-notone.sqlite", std::process::id()));
+        let path = dir.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        let mut record = make_test_record();
+        record.tone = None;
+        export_sqlite(&[record], path).unwrap();
+
+        let conn = Connection::open(path).unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM tone", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 0);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn export_sqlite_is_idempotent_when_run_twice_against_the_same_file() {
+        let dir = std::env::temp_dir().join(format!("newsfresh-test-{}-repeat.sqlite", std::process::id()));
+        let path = dir.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        export_sqlite(&[make_test_record()], path).unwrap();
+        export_sqlite(&[make_test_record()], path).unwrap();
+
+        let conn = Connection::open(path).unwrap();
+        let gkg_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM gkg WHERE gkg_record_id = '1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(gkg_count, 1);
+
+        let theme_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM themes WHERE gkg_record_id = '1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(theme_count, 2);
+
+        let person_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM persons WHERE gkg_record_id = '1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(person_count, 2);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_util::StreamExt;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use crate::error::NewsfreshError;
+
+use super::client::{self, ClientConfig};
+
+/// Validators and metadata from a previous response, used to make the next
+/// request conditional so an unchanged resource doesn't get re-downloaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// Cached response body, for [`HttpCache::fetch_text`] callers (e.g.
+    /// `lastupdate.txt`) that need the content itself rather than a
+    /// downloaded file on disk.
+    body: Option<String>,
+    fetched_at: u64,
+}
+
+/// A persisted, URL-keyed HTTP cache backed by a small on-disk JSON
+/// manifest, holding the ETag/Last-Modified validators needed to make
+/// conditional GET requests.
+///
+/// Unlike [`super::download_cache::DownloadCache`], which keys on a known
+/// MD5/size from `lastupdate.txt` and so only ever covers GKG archives, this
+/// works for any URL — including `lastupdate.txt` itself, which `watch`
+/// polls every 15 minutes and which has no MD5/size of its own to key on.
+pub struct HttpCache {
+    manifest_path: PathBuf,
+    entries: HashMap<String, Validators>,
+}
+
+impl HttpCache {
+    /// Opens (creating if needed) the manifest at `manifest_path`.
+    pub fn open(manifest_path: PathBuf) -> Result<Self, NewsfreshError> {
+        let entries = match std::fs::read_to_string(&manifest_path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_default(),
+            Err(_) => HashMap::new(),
+        };
+        Ok(Self {
+            manifest_path,
+            entries,
+        })
+    }
+
+    fn save(&self) -> Result<(), NewsfreshError> {
+        if let Some(parent) = self.manifest_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let text = serde_json::to_string_pretty(&self.entries)?;
+        std::fs::write(&self.manifest_path, text)?;
+        Ok(())
+    }
+
+    fn fresh_enough(cached: &Validators, max_age: Option<Duration>) -> bool {
+        let Some(max_age) = max_age else {
+            return false;
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(cached.fetched_at);
+        now.saturating_sub(cached.fetched_at) <= max_age.as_secs()
+    }
+
+    fn store(
+        &mut self,
+        url: &str,
+        etag: Option<String>,
+        last_modified: Option<String>,
+        body: Option<String>,
+    ) -> Result<(), NewsfreshError> {
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.entries.insert(
+            url.to_string(),
+            Validators {
+                etag,
+                last_modified,
+                body,
+                fetched_at,
+            },
+        );
+        self.save()
+    }
+
+    /// Fetches `url` as text. Reuses the cached body with no network request
+    /// at all when it's within `max_age`, otherwise issues a conditional GET
+    /// (`If-None-Match`/`If-Modified-Since`) and reuses the cached body on a
+    /// `304`. Pass `bypass: true` to skip the cache entirely.
+    pub async fn fetch_text(
+        &mut self,
+        url: &str,
+        config: &ClientConfig,
+        max_age: Option<Duration>,
+        bypass: bool,
+    ) -> Result<String, NewsfreshError> {
+        let cached = (!bypass).then(|| self.entries.get(url).cloned()).flatten();
+
+        if let Some(ref cached) = cached {
+            if Self::fresh_enough(cached, max_age) {
+                if let Some(ref body) = cached.body {
+                    debug!("Cache hit (fresh) for {url}");
+                    return Ok(body.clone());
+                }
+            }
+        }
+
+        let client = client::build_client(config)?;
+        let mut request = client.get(url);
+        if let Some(ref cached) = cached {
+            if let Some(ref etag) = cached.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(ref last_modified) = cached.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let resp = request.send().await?;
+        if resp.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(body) = cached.and_then(|c| c.body) {
+                debug!("Cache hit (304) for {url}");
+                return Ok(body);
+            }
+        }
+
+        let resp = resp.error_for_status()?;
+        let (etag, last_modified) = extract_validators(&resp);
+        let body = resp.text().await?;
+        self.store(url, etag, last_modified, Some(body.clone()))?;
+        Ok(body)
+    }
+
+    /// Downloads `url` into `dest` unless the server confirms (via a `304`)
+    /// or `max_age` confirms that the previously cached copy at `dest` is
+    /// still current. Returns `true` if a fresh copy was downloaded, `false`
+    /// on a cache hit, so a caller can report cache hits distinctly from
+    /// real downloads in its progress output.
+    pub async fn fetch_file(
+        &mut self,
+        url: &str,
+        dest: &Path,
+        config: &ClientConfig,
+        max_age: Option<Duration>,
+        bypass: bool,
+    ) -> Result<bool, NewsfreshError> {
+        let cached = (!bypass && dest.exists())
+            .then(|| self.entries.get(url).cloned())
+            .flatten();
+
+        if let Some(ref cached) = cached {
+            if Self::fresh_enough(cached, max_age) {
+                debug!("Cache hit (fresh) for {url}, skipping download");
+                return Ok(false);
+            }
+        }
+
+        let client = client::build_client(config)?;
+        let mut request = client.get(url);
+        if let Some(ref cached) = cached {
+            if let Some(ref etag) = cached.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(ref last_modified) = cached.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let resp = request.send().await?;
+        if resp.status() == reqwest::StatusCode::NOT_MODIFIED {
+            debug!("Cache hit (304) for {url}, skipping download");
+            return Ok(false);
+        }
+
+        let resp = resp.error_for_status()?;
+        let (etag, last_modified) = extract_validators(&resp);
+
+        let total_size = resp.content_length().unwrap_or(0);
+        let pb = ProgressBar::new(total_size);
+        pb.set_style(
+            ProgressStyle::with_template(
+                "{msg} [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec})",
+            )
+            .unwrap()
+            .progress_chars("=> "),
+        );
+        pb.set_message("Downloading");
+
+        let mut file = tokio::fs::File::create(dest).await?;
+        let mut stream = resp.bytes_stream();
+        let mut downloaded: u64 = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk).await?;
+            downloaded += chunk.len() as u64;
+            pb.set_position(downloaded);
+        }
+        file.flush().await?;
+        pb.finish_with_message("Downloaded");
+
+        self.store(url, etag, last_modified, None)?;
+        Ok(true)
+    }
+}
+
+fn extract_validators(resp: &reqwest::Response) -> (Option<String>, Option<String>) {
+    let etag = resp
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let last_modified = resp
+        .headers()
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    (etag, last_modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_cache_has_no_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = HttpCache::open(dir.path().join("http-cache.json")).unwrap();
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn manifest_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("http-cache.json");
+
+        let mut cache = HttpCache::open(manifest_path.clone()).unwrap();
+        cache
+            .store(
+                "http://example.com/lastupdate.txt",
+                Some("etag-1".to_string()),
+                None,
+                Some("body text".to_string()),
+            )
+            .unwrap();
+
+        let reopened = HttpCache::open(manifest_path).unwrap();
+        let cached = reopened.entries.get("http://example.com/lastupdate.txt").unwrap();
+        assert_eq!(cached.etag.as_deref(), Some("etag-1"));
+        assert_eq!(cached.body.as_deref(), Some("body text"));
+    }
+
+    #[test]
+    fn fresh_enough_is_false_without_max_age() {
+        let cached = Validators {
+            fetched_at: 0,
+            ..Validators::default()
+        };
+        assert!(!HttpCache::fresh_enough(&cached, None));
+    }
+
+    #[test]
+    fn fresh_enough_is_true_within_max_age() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let cached = Validators {
+            fetched_at: now,
+            ..Validators::default()
+        };
+        assert!(HttpCache::fresh_enough(&cached, Some(Duration::from_secs(60))));
+    }
+
+    #[test]
+    fn fresh_enough_is_false_once_max_age_elapses() {
+        let cached = Validators {
+            fetched_at: 0,
+            ..Validators::default()
+        };
+        assert!(!HttpCache::fresh_enough(&cached, Some(Duration::from_secs(60))));
+    }
+}
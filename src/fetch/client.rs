@@ -6,20 +6,47 @@ use tokio::io::AsyncWriteExt;
 
 use crate::error::NewsfreshError;
 
-fn build_client() -> Result<reqwest::Client, NewsfreshError> {
-    Ok(reqwest::Client::builder()
-        .danger_accept_invalid_certs(true)
-        .build()?)
+/// Controls how the underlying HTTP client validates TLS connections.
+///
+/// Certificate validation is on by default. `insecure` is an explicit,
+/// opt-in escape hatch for environments with self-signed or otherwise
+/// untrusted certs (e.g. a corporate proxy) — it is never enabled silently.
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    pub insecure: bool,
+}
+
+pub(crate) fn build_client(config: &ClientConfig) -> Result<reqwest::Client, NewsfreshError> {
+    let mut builder = reqwest::Client::builder();
+    if config.insecure {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+    Ok(builder.build()?)
 }
 
 pub async fn fetch_text(url: &str) -> Result<String, NewsfreshError> {
-    let client = build_client()?;
+    fetch_text_with_config(url, &ClientConfig::default()).await
+}
+
+pub async fn fetch_text_with_config(
+    url: &str,
+    config: &ClientConfig,
+) -> Result<String, NewsfreshError> {
+    let client = build_client(config)?;
     let resp = client.get(url).send().await?.error_for_status()?;
     Ok(resp.text().await?)
 }
 
 pub async fn download_file(url: &str, dest: &Path) -> Result<PathBuf, NewsfreshError> {
-    let client = build_client()?;
+    download_file_with_config(url, dest, &ClientConfig::default()).await
+}
+
+pub async fn download_file_with_config(
+    url: &str,
+    dest: &Path,
+    config: &ClientConfig,
+) -> Result<PathBuf, NewsfreshError> {
+    let client = build_client(config)?;
     let resp = client.get(url).send().await?.error_for_status()?;
 
     let total_size = resp.content_length().unwrap_or(0);
@@ -64,3 +91,13 @@ pub fn historical_url(date: &str) -> String {
         "http://data.gdeltproject.org/gdeltv2/{date}.gkg.csv.zip"
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_client_config_is_secure() {
+        assert!(!ClientConfig::default().insecure);
+    }
+}
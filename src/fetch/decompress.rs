@@ -1,6 +1,8 @@
-use std::io::Read;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 
+use sha2::{Digest, Sha256};
+
 use crate::error::NewsfreshError;
 
 pub fn extract_gkg_from_zip(zip_path: &Path, output_dir: &Path) -> Result<PathBuf, NewsfreshError> {
@@ -21,8 +23,89 @@ pub fn extract_gkg_from_zip(zip_path: &Path, output_dir: &Path) -> Result<PathBu
     Err(NewsfreshError::Other("No CSV file found in ZIP".into()))
 }
 
-/// Read the GKG CSV content directly from a zip file without extracting to disk.
-pub fn read_gkg_from_zip(zip_path: &Path) -> Result<String, NewsfreshError> {
+/// Provenance recorded for a single extracted GKG CSV file, so a
+/// download-then-parse pipeline can log or store what it actually ingested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractionManifest {
+    pub file_name: String,
+    pub uncompressed_size: u64,
+    pub digest: String,
+    pub record_count: usize,
+}
+
+/// Counts GKG records in `bytes` with a quick line scan: one record per line,
+/// plus a final unterminated line if the file doesn't end in a newline.
+fn count_lines(bytes: &[u8]) -> usize {
+    if bytes.is_empty() {
+        return 0;
+    }
+    let newlines = bytes.iter().filter(|&&b| b == b'\n').count();
+    if bytes.last() == Some(&b'\n') {
+        newlines
+    } else {
+        newlines + 1
+    }
+}
+
+/// Computes a [`ExtractionManifest`] for the extracted CSV at `path`, hashing
+/// it with SHA-256. When `expected_checksum` is `Some`, also verifies it
+/// against that digest before returning the manifest, accepting either a
+/// SHA-256 (64 hex chars) or an MD5 (32 hex chars) checksum — GDELT's own
+/// file listings only publish MD5s — and returning
+/// [`NewsfreshError::Integrity`] on a mismatch.
+pub fn verify_and_manifest(
+    path: &Path,
+    expected_checksum: Option<&str>,
+) -> Result<ExtractionManifest, NewsfreshError> {
+    let bytes = std::fs::read(path)?;
+    let sha256_digest = format!("{:x}", Sha256::digest(&bytes));
+
+    if let Some(expected) = expected_checksum {
+        let (algorithm, actual) = match expected.len() {
+            32 => ("MD5", format!("{:x}", md5::compute(&bytes))),
+            _ => ("SHA-256", sha256_digest.clone()),
+        };
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(NewsfreshError::Integrity {
+                path: path.display().to_string(),
+                algorithm,
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    Ok(ExtractionManifest {
+        file_name,
+        uncompressed_size: bytes.len() as u64,
+        digest: sha256_digest,
+        record_count: count_lines(&bytes),
+    })
+}
+
+/// Like [`extract_gkg_from_zip`], but also verifies the extracted CSV against
+/// `expected_checksum` (when given) and returns its [`ExtractionManifest`]
+/// instead of just the path — the safe entry point for a download-then-parse
+/// pipeline that must not trust a truncated or corrupted 15-minute pull.
+pub fn extract_gkg_from_zip_verified(
+    zip_path: &Path,
+    output_dir: &Path,
+    expected_checksum: Option<&str>,
+) -> Result<ExtractionManifest, NewsfreshError> {
+    let out_path = extract_gkg_from_zip(zip_path, output_dir)?;
+    verify_and_manifest(&out_path, expected_checksum)
+}
+
+/// Streams the GKG CSV entry out of `zip_path` without ever materializing the
+/// whole decompressed file as a single in-memory buffer: the entry is copied
+/// chunk-by-chunk into an unnamed temp file, and reads from the returned
+/// reader pull from that temp file on demand.
+pub fn stream_gkg_from_zip(zip_path: &Path) -> Result<Box<dyn BufRead>, NewsfreshError> {
     let file = std::fs::File::open(zip_path)?;
     let mut archive = zip::ZipArchive::new(file)?;
 
@@ -30,11 +113,98 @@ pub fn read_gkg_from_zip(zip_path: &Path) -> Result<String, NewsfreshError> {
         let mut entry = archive.by_index(i)?;
         let name = entry.name().to_string();
         if name.ends_with(".csv") {
-            let mut content = String::new();
-            entry.read_to_string(&mut content)?;
-            return Ok(content);
+            let mut temp = tempfile::tempfile()?;
+            std::io::copy(&mut entry, &mut temp)?;
+            temp.seek(SeekFrom::Start(0))?;
+            return Ok(Box::new(BufReader::new(temp)));
         }
     }
 
     Err(NewsfreshError::Other("No CSV file found in ZIP".into()))
 }
+
+/// Lists every `.csv` entry name in `zip_path`, in the archive's own order —
+/// unlike [`stream_gkg_from_zip`], which only ever returns the first one.
+/// Lets a caller stream every entry in a multi-CSV archive one at a time via
+/// [`stream_gkg_zip_entry`].
+pub fn list_gkg_zip_entries(zip_path: &Path) -> Result<Vec<String>, NewsfreshError> {
+    let file = std::fs::File::open(zip_path)?;
+    let archive = zip::ZipArchive::new(file)?;
+    Ok(archive.file_names().filter(|name| name.ends_with(".csv")).map(String::from).collect())
+}
+
+/// Like [`stream_gkg_from_zip`], but extracts the specific entry named
+/// `entry_name` instead of always taking the first `.csv` found.
+pub fn stream_gkg_zip_entry(zip_path: &Path, entry_name: &str) -> Result<Box<dyn BufRead>, NewsfreshError> {
+    let file = std::fs::File::open(zip_path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+    let mut entry = archive.by_name(entry_name)?;
+
+    let mut temp = tempfile::tempfile()?;
+    std::io::copy(&mut entry, &mut temp)?;
+    temp.seek(SeekFrom::Start(0))?;
+    Ok(Box::new(BufReader::new(temp)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_lines_counts_a_final_unterminated_line() {
+        assert_eq!(count_lines(b"a\nb\nc"), 3);
+    }
+
+    #[test]
+    fn count_lines_does_not_double_count_a_trailing_newline() {
+        assert_eq!(count_lines(b"a\nb\nc\n"), 3);
+    }
+
+    #[test]
+    fn count_lines_returns_zero_for_empty_input() {
+        assert_eq!(count_lines(b""), 0);
+    }
+
+    #[test]
+    fn verify_and_manifest_builds_a_manifest_without_a_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("20250217150000.gkg.csv");
+        std::fs::write(&path, b"record one\nrecord two\n").unwrap();
+
+        let manifest = verify_and_manifest(&path, None).unwrap();
+        assert_eq!(manifest.file_name, "20250217150000.gkg.csv");
+        assert_eq!(manifest.uncompressed_size, 22);
+        assert_eq!(manifest.record_count, 2);
+        assert_eq!(manifest.digest.len(), 64);
+    }
+
+    #[test]
+    fn verify_and_manifest_accepts_a_matching_sha256_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, b"hello gdelt").unwrap();
+
+        let digest = format!("{:x}", Sha256::digest(b"hello gdelt"));
+        assert!(verify_and_manifest(&path, Some(&digest)).is_ok());
+    }
+
+    #[test]
+    fn verify_and_manifest_accepts_a_matching_md5_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, b"hello gdelt").unwrap();
+
+        let digest = format!("{:x}", md5::compute(b"hello gdelt"));
+        assert!(verify_and_manifest(&path, Some(&digest)).is_ok());
+    }
+
+    #[test]
+    fn verify_and_manifest_rejects_a_mismatched_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, b"hello gdelt").unwrap();
+
+        let err = verify_and_manifest(&path, Some("deadbeef")).unwrap_err();
+        assert!(matches!(err, NewsfreshError::Integrity { .. }));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::NewsfreshError;
+
+use super::lastupdate::LastUpdateEntry;
+
+/// One cached download, keyed by its manifest MD5 in [`DownloadCache::entries`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    size_bytes: u64,
+    fetched_at: u64,
+}
+
+/// A persisted, MD5-keyed cache of downloaded GKG archives, backed by a JSON
+/// index (`cache.json`) alongside the cached files themselves.
+///
+/// Unlike [`super::cache::HttpCache`], which keys on URL and still asks the
+/// server (via a conditional GET) whether a copy is current, this keys on the
+/// manifest MD5 — `query`/`analyze` runs against the same 15-minute slice
+/// reuse the archive without any network round-trip.
+pub struct DownloadCache {
+    files_dir: PathBuf,
+    index_path: PathBuf,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl DownloadCache {
+    /// Opens (creating if needed) the cache rooted at `base_dir`, typically
+    /// `persisted-storage/`.
+    pub fn open(base_dir: &Path) -> Result<Self, NewsfreshError> {
+        let files_dir = base_dir.join("cache");
+        std::fs::create_dir_all(&files_dir)?;
+
+        let index_path = base_dir.join("cache.json");
+        let entries = match std::fs::read_to_string(&index_path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_default(),
+            Err(_) => HashMap::new(),
+        };
+
+        Ok(Self {
+            files_dir,
+            index_path,
+            entries,
+        })
+    }
+
+    fn cached_path(&self, md5_hash: &str) -> PathBuf {
+        self.files_dir.join(format!("{md5_hash}.zip"))
+    }
+
+    /// Returns the path to a cached copy of `entry`'s archive, if one exists,
+    /// matches `entry`'s URL and size, and (when `ttl` is given) isn't older
+    /// than `ttl`.
+    pub fn get(&self, entry: &LastUpdateEntry, ttl: Option<Duration>) -> Option<PathBuf> {
+        let cached = self.entries.get(&entry.md5_hash)?;
+        if cached.url != entry.url || cached.size_bytes != entry.size_bytes {
+            return None;
+        }
+
+        let path = self.cached_path(&entry.md5_hash);
+        if !path.exists() {
+            return None;
+        }
+
+        if let Some(ttl) = ttl {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(cached.fetched_at);
+            if now.saturating_sub(cached.fetched_at) > ttl.as_secs() {
+                return None;
+            }
+        }
+
+        Some(path)
+    }
+
+    /// Registers `downloaded_path` (already verified against `entry`) in the
+    /// cache, persisting the updated index to disk.
+    pub fn insert(
+        &mut self,
+        entry: &LastUpdateEntry,
+        downloaded_path: &Path,
+    ) -> Result<(), NewsfreshError> {
+        let cached_path = self.cached_path(&entry.md5_hash);
+        if downloaded_path != cached_path {
+            std::fs::copy(downloaded_path, &cached_path)?;
+        }
+
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.entries.insert(
+            entry.md5_hash.clone(),
+            CacheEntry {
+                url: entry.url.clone(),
+                size_bytes: entry.size_bytes,
+                fetched_at,
+            },
+        );
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), NewsfreshError> {
+        let text = serde_json::to_string_pretty(&self.entries)?;
+        std::fs::write(&self.index_path, text)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_entry(md5_hash: &str) -> LastUpdateEntry {
+        LastUpdateEntry {
+            size_bytes: 4,
+            md5_hash: md5_hash.to_string(),
+            url: "http://example.com/20250217.gkg.csv.zip".into(),
+        }
+    }
+
+    #[test]
+    fn miss_when_never_inserted() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DownloadCache::open(dir.path()).unwrap();
+        assert!(cache.get(&make_entry("abc"), None).is_none());
+    }
+
+    #[test]
+    fn hit_after_insert_and_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let downloaded = dir.path().join("download.zip");
+        std::fs::write(&downloaded, b"data").unwrap();
+
+        let entry = make_entry("abc");
+        let mut cache = DownloadCache::open(dir.path()).unwrap();
+        cache.insert(&entry, &downloaded).unwrap();
+        assert!(cache.get(&entry, None).is_some());
+
+        let reopened = DownloadCache::open(dir.path()).unwrap();
+        assert!(reopened.get(&entry, None).is_some());
+    }
+
+    #[test]
+    fn stale_entry_is_ignored_when_ttl_exceeded() {
+        let dir = tempfile::tempdir().unwrap();
+        let downloaded = dir.path().join("download.zip");
+        std::fs::write(&downloaded, b"data").unwrap();
+
+        let entry = make_entry("abc");
+        let mut cache = DownloadCache::open(dir.path()).unwrap();
+        cache.insert(&entry, &downloaded).unwrap();
+        cache.entries.get_mut("abc").unwrap().fetched_at = 0;
+
+        assert!(cache.get(&entry, Some(Duration::from_secs(60))).is_none());
+    }
+
+    #[test]
+    fn mismatched_size_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let downloaded = dir.path().join("download.zip");
+        std::fs::write(&downloaded, b"data").unwrap();
+
+        let entry = make_entry("abc");
+        let mut cache = DownloadCache::open(dir.path()).unwrap();
+        cache.insert(&entry, &downloaded).unwrap();
+
+        let mut changed = entry.clone();
+        changed.size_bytes = 999;
+        assert!(cache.get(&changed, None).is_none());
+    }
+}
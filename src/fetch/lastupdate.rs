@@ -32,6 +32,16 @@ pub fn find_gkg_url(entries: &[LastUpdateEntry]) -> Result<String, NewsfreshErro
         .ok_or(NewsfreshError::NoGkgFile)
 }
 
+/// Like [`find_gkg_url`], but returns the full entry (MD5 + byte size) so
+/// the download can be verified afterward.
+pub fn find_gkg_entry(entries: &[LastUpdateEntry]) -> Result<LastUpdateEntry, NewsfreshError> {
+    entries
+        .iter()
+        .find(|e| e.url.contains(".gkg.csv"))
+        .cloned()
+        .ok_or(NewsfreshError::NoGkgFile)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,8 @@
+pub mod cache;
+pub mod client;
+pub mod decompress;
+pub mod download_cache;
+pub mod lastupdate;
+pub mod range;
+pub mod verify;
+pub mod watch;
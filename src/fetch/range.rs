@@ -0,0 +1,67 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::error::NewsfreshError;
+
+const GDELT_TIMESTAMP_FMT: &str = "%Y%m%d%H%M%S";
+
+/// Enumerates every 15-minute GDELT timestamp in `[from, to]` (inclusive),
+/// matching GDELT's publication cadence, so a date range can be materialized
+/// as one `historical_url` per slice.
+pub fn enumerate_slices(from: &str, to: &str) -> Result<Vec<String>, NewsfreshError> {
+    let start = NaiveDateTime::parse_from_str(from, GDELT_TIMESTAMP_FMT)
+        .map_err(|_| NewsfreshError::InvalidDate(from.to_string()))?;
+    let end = NaiveDateTime::parse_from_str(to, GDELT_TIMESTAMP_FMT)
+        .map_err(|_| NewsfreshError::InvalidDate(to.to_string()))?;
+    if end < start {
+        return Err(NewsfreshError::InvalidDate(format!(
+            "range end {to} is before range start {from}"
+        )));
+    }
+
+    let step = Duration::minutes(15);
+    let mut slices = Vec::new();
+    let mut current = start;
+    while current <= end {
+        slices.push(current.format(GDELT_TIMESTAMP_FMT).to_string());
+        current += step;
+    }
+    Ok(slices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enumerates_quarter_hour_slices() {
+        let slices = enumerate_slices("20250217120000", "20250217130000").unwrap();
+        assert_eq!(
+            slices,
+            vec![
+                "20250217120000",
+                "20250217121500",
+                "20250217123000",
+                "20250217124500",
+                "20250217130000",
+            ]
+        );
+    }
+
+    #[test]
+    fn single_instant_range_yields_one_slice() {
+        let slices = enumerate_slices("20250217120000", "20250217120000").unwrap();
+        assert_eq!(slices, vec!["20250217120000"]);
+    }
+
+    #[test]
+    fn rejects_end_before_start() {
+        let result = enumerate_slices("20250217130000", "20250217120000");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_timestamp() {
+        let result = enumerate_slices("not-a-date", "20250217120000");
+        assert!(result.is_err());
+    }
+}
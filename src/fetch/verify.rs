@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use crate::error::NewsfreshError;
+
+use super::lastupdate::LastUpdateEntry;
+
+/// Verifies a downloaded file against the MD5 hash and byte size GDELT
+/// published for it in `lastupdate.txt`, catching truncated or corrupted
+/// downloads before they're handed to the ZIP extractor.
+pub fn verify_download(path: &Path, entry: &LastUpdateEntry) -> Result<(), NewsfreshError> {
+    let bytes = std::fs::read(path)?;
+
+    if bytes.len() as u64 != entry.size_bytes {
+        return Err(NewsfreshError::SizeMismatch {
+            path: path.display().to_string(),
+            expected: entry.size_bytes,
+            actual: bytes.len() as u64,
+        });
+    }
+
+    let digest = format!("{:x}", md5::compute(&bytes));
+    if !digest.eq_ignore_ascii_case(&entry.md5_hash) {
+        return Err(NewsfreshError::ChecksumMismatch {
+            path: path.display().to_string(),
+            expected: entry.md5_hash.clone(),
+            actual: digest,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_file_passes_verification() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.bin");
+        std::fs::write(&path, b"hello gdelt").unwrap();
+
+        let digest = format!("{:x}", md5::compute(b"hello gdelt"));
+        let entry = LastUpdateEntry {
+            size_bytes: 11,
+            md5_hash: digest,
+            url: "http://example.com/data.bin".into(),
+        };
+
+        assert!(verify_download(&path, &entry).is_ok());
+    }
+
+    #[test]
+    fn size_mismatch_is_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.bin");
+        std::fs::write(&path, b"hello gdelt").unwrap();
+
+        let entry = LastUpdateEntry {
+            size_bytes: 999,
+            md5_hash: "deadbeef".into(),
+            url: "http://example.com/data.bin".into(),
+        };
+
+        let err = verify_download(&path, &entry).unwrap_err();
+        assert!(matches!(err, NewsfreshError::SizeMismatch { .. }));
+    }
+
+    #[test]
+    fn checksum_mismatch_is_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.bin");
+        std::fs::write(&path, b"hello gdelt").unwrap();
+
+        let entry = LastUpdateEntry {
+            size_bytes: 11,
+            md5_hash: "deadbeef".into(),
+            url: "http://example.com/data.bin".into(),
+        };
+
+        let err = verify_download(&path, &entry).unwrap_err();
+        assert!(matches!(err, NewsfreshError::ChecksumMismatch { .. }));
+    }
+}
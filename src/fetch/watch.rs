@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::error::NewsfreshError;
+use crate::output::OutputFormatter;
+use crate::parse;
+
+use super::cache::HttpCache;
+use super::client::ClientConfig;
+use super::{client, decompress, lastupdate, verify};
+
+/// Configuration for the long-lived polling ingester.
+pub struct WatchConfig {
+    /// How often to poll `lastupdate.txt`. GDELT republishes every 15
+    /// minutes, so polling faster than that just re-reads the same file.
+    pub interval: Duration,
+    pub translation: bool,
+    /// Where the last-processed file id is persisted, so a restart doesn't
+    /// re-ingest a file it already pushed downstream.
+    pub state_file: PathBuf,
+    /// Scratch directory for downloaded/extracted files.
+    pub data_dir: PathBuf,
+    /// Maximum consecutive HTTP-error retries before a poll gives up for
+    /// this cycle (the next scheduled poll still happens).
+    pub max_retries: u32,
+    /// TLS validation settings for the underlying HTTP client.
+    pub client: ClientConfig,
+    /// Path to the persisted [`HttpCache`] manifest used to make each
+    /// `lastupdate.txt` poll a conditional GET.
+    pub http_cache_path: PathBuf,
+    /// Treat a cached `lastupdate.txt` response younger than this as current
+    /// without even issuing a conditional GET.
+    pub cache_ttl: Option<Duration>,
+    /// Skip the HTTP cache entirely and always issue a fresh request.
+    pub bypass_cache: bool,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(15 * 60),
+            translation: false,
+            state_file: PathBuf::from(".newsfresh-watch-state"),
+            data_dir: PathBuf::from("./data"),
+            max_retries: 5,
+            client: ClientConfig::default(),
+            http_cache_path: PathBuf::from("./data/http-cache.json"),
+            cache_ttl: None,
+            bypass_cache: false,
+        }
+    }
+}
+
+fn load_last_processed(state_file: &Path) -> Option<String> {
+    std::fs::read_to_string(state_file)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn save_last_processed(state_file: &Path, id: &str) -> Result<(), NewsfreshError> {
+    std::fs::write(state_file, id)?;
+    Ok(())
+}
+
+/// Extracts the GDELT file id (the leading timestamp) from a `.gkg.csv.zip`
+/// URL, e.g. `.../20250217150000.gkg.csv.zip` -> `20250217150000`.
+fn file_id(url: &str) -> &str {
+    url.rsplit('/')
+        .next()
+        .unwrap_or(url)
+        .trim_end_matches(".gkg.csv.zip")
+}
+
+/// Fetches text through `cache` with bounded exponential backoff on HTTP
+/// errors.
+async fn fetch_text_with_retry(
+    cache: &mut HttpCache,
+    url: &str,
+    config: &ClientConfig,
+    cache_ttl: Option<Duration>,
+    bypass_cache: bool,
+    max_retries: u32,
+) -> Result<String, NewsfreshError> {
+    let mut attempt = 0;
+    loop {
+        match cache.fetch_text(url, config, cache_ttl, bypass_cache).await {
+            Ok(text) => return Ok(text),
+            Err(e) if attempt < max_retries => {
+                let backoff = Duration::from_secs(2u64.saturating_pow(attempt));
+                warn!("fetch_text({url}) failed (attempt {attempt}): {e}; retrying in {backoff:?}");
+                tokio::time::sleep(backoff).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Runs a single poll cycle: checks for a newer GKG file, and if found,
+/// downloads, extracts, parses, and writes its records into `formatter`.
+///
+/// Returns `Ok(None)` when the latest file has already been processed, or
+/// `Ok(Some(count))` with the number of records ingested.
+pub async fn poll_once(
+    config: &WatchConfig,
+    formatter: &mut dyn OutputFormatter,
+) -> Result<Option<usize>, NewsfreshError> {
+    std::fs::create_dir_all(&config.data_dir)?;
+
+    let mut http_cache = HttpCache::open(config.http_cache_path.clone())?;
+    let lastupdate_text = fetch_text_with_retry(
+        &mut http_cache,
+        client::lastupdate_url(config.translation),
+        &config.client,
+        config.cache_ttl,
+        config.bypass_cache,
+        config.max_retries,
+    )
+    .await?;
+    let entries = lastupdate::parse_lastupdate(&lastupdate_text);
+    let entry = lastupdate::find_gkg_entry(&entries)?;
+    let id = file_id(&entry.url).to_string();
+
+    if load_last_processed(&config.state_file).as_deref() == Some(id.as_str()) {
+        info!("Already processed {id}, skipping");
+        return Ok(None);
+    }
+
+    let filename = entry.url.rsplit('/').next().unwrap_or("gkg.csv.zip");
+    let zip_path = config.data_dir.join(filename);
+
+    info!("Downloading new GKG file: {}", entry.url);
+    client::download_file_with_config(&entry.url, &zip_path, &config.client).await?;
+    verify::verify_download(&zip_path, &entry)?;
+    let manifest = decompress::extract_gkg_from_zip_verified(&zip_path, &config.data_dir, None)?;
+    let csv_path = config.data_dir.join(&manifest.file_name);
+    std::fs::remove_file(&zip_path)?;
+
+    let file = std::fs::File::open(&csv_path)?;
+    let reader = std::io::BufReader::new(file);
+    let gkg_reader = parse::GkgReader::new(reader);
+
+    let mut count = 0;
+    for result in gkg_reader {
+        let (line_num, line) = result?;
+        match parse::parse_record(&line, line_num) {
+            Ok(record) => {
+                formatter.write_record(&record)?;
+                count += 1;
+            }
+            Err(e) => warn!("Skipping line {line_num}: {e}"),
+        }
+    }
+
+    save_last_processed(&config.state_file, &id)?;
+    Ok(Some(count))
+}
+
+/// Runs forever, polling every `config.interval` and pushing new records
+/// into `formatter`. Errors from a single poll cycle are logged and do not
+/// stop the watcher — it simply waits for the next scheduled poll.
+pub async fn run(config: WatchConfig, formatter: &mut dyn OutputFormatter) -> ! {
+    loop {
+        match poll_once(&config, formatter).await {
+            Ok(Some(count)) => info!("Ingested {count} new records"),
+            Ok(None) => {}
+            Err(e) => warn!("Poll cycle failed: {e}"),
+        }
+        tokio::time::sleep(config.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_id_strips_extension() {
+        assert_eq!(
+            file_id("http://data.gdeltproject.org/gdeltv2/20250217150000.gkg.csv.zip"),
+            "20250217150000"
+        );
+    }
+
+    #[test]
+    fn last_processed_roundtrips_through_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("state");
+        assert!(load_last_processed(&state_file).is_none());
+        save_last_processed(&state_file, "20250217150000").unwrap();
+        assert_eq!(
+            load_last_processed(&state_file),
+            Some("20250217150000".to_string())
+        );
+    }
+}
@@ -0,0 +1,622 @@
+use crate::error::NewsfreshError;
+use crate::model::GkgRecord;
+
+use super::predicates::{
+    CountryFilter, DateRangeFilter, HasImageFilter, HasQuoteFilter, LocationFilter, OrgFilter,
+    PersonFilter, SourceFilter, ThemeFilter, ThemeFuzzyMatchFilter, ToneRangeFilter,
+};
+use super::RecordFilter;
+
+/// Maximum parser recursion depth [`parse_filter`] will descend before giving
+/// up, so adversarial input (e.g. thousands of nested parens) can't blow the
+/// parser's call stack. Each `NOT` adds one level and each parenthesized
+/// group adds four, so roughly 500 nested parens already exceed it.
+const MAX_DEPTH: usize = 2000;
+
+/// Matches a record only when every inner filter does — the tree form of
+/// [`super::CompositeFilter`]'s all-must-match rule, for use inside a
+/// parsed boolean expression.
+pub struct AndFilter(pub Vec<Box<dyn RecordFilter>>);
+
+impl RecordFilter for AndFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        self.0.iter().all(|f| f.matches(record))
+    }
+}
+
+/// Matches a record when any inner filter does.
+pub struct OrFilter(pub Vec<Box<dyn RecordFilter>>);
+
+impl RecordFilter for OrFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        self.0.iter().any(|f| f.matches(record))
+    }
+}
+
+/// Matches a record when the inner filter does not.
+pub struct NotFilter(pub Box<dyn RecordFilter>);
+
+impl RecordFilter for NotFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        !self.0.matches(record)
+    }
+}
+
+/// Parses a MeiliSearch-style boolean filter expression into a tree of
+/// [`RecordFilter`]s, e.g.
+/// `"person = trump AND (tone < 0 OR country IN [US, UK])"`.
+///
+/// Supported leaves: `theme = X`, `theme_fuzzy = X` (typo-tolerant, see
+/// [`ThemeFuzzyMatchFilter`]), `tone > -5`, `tone <= 0`, `date >=
+/// 20250101000000`, `country IN [US, UK]`, `source CONTAINS nytimes`, `image
+/// IS NULL` / `IS NOT NULL`, and `quotations IS EMPTY` / `IS NOT EMPTY`. For
+/// `tone` and `date`, `>`/`<` are strict (exclusive) bounds and `>=`/`<=` are
+/// inclusive, so a record exactly at the boundary value matches `>=`/`<=`
+/// but not `>`/`<`.
+/// Leaves combine with `AND`, `OR`, `NOT`, and parenthesized grouping, each
+/// mapped onto the existing [`super::predicates`] filter structs so
+/// evaluation stays the trait-object `matches` call.
+pub fn parse_filter(input: &str) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let filter = parser.parse_or(0)?;
+    if parser.pos != parser.tokens.len() {
+        return Err(NewsfreshError::FilterExpression(format!(
+            "unexpected trailing token {:?}",
+            parser.tokens[parser.pos]
+        )));
+    }
+    Ok(filter)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    And,
+    Or,
+    Not,
+    Is,
+    In,
+    Contains,
+    Null,
+    Empty,
+    Eq,
+    Ne,
+    Ge,
+    Le,
+    Gt,
+    Lt,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, NewsfreshError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '[' => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '=' => {
+                tokens.push(Token::Eq);
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Ne);
+                i += 2;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Ge);
+                i += 2;
+            }
+            '>' => {
+                tokens.push(Token::Gt);
+                i += 1;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Le);
+                i += 2;
+            }
+            '<' => {
+                tokens.push(Token::Lt);
+                i += 1;
+            }
+            '"' => {
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != '"' {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(NewsfreshError::FilterExpression(
+                        "unterminated string literal".to_string(),
+                    ));
+                }
+                tokens.push(Token::Str(chars[start..i].iter().collect()));
+                i += 1;
+            }
+            '-' if chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()));
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '-' || chars[i] == '.')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(keyword_or_ident(word));
+            }
+            _ => {
+                return Err(NewsfreshError::FilterExpression(format!(
+                    "unexpected character '{c}'"
+                )))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn keyword_or_ident(word: String) -> Token {
+    match word.to_uppercase().as_str() {
+        "AND" => Token::And,
+        "OR" => Token::Or,
+        "NOT" => Token::Not,
+        "IS" => Token::Is,
+        "IN" => Token::In,
+        "CONTAINS" => Token::Contains,
+        "NULL" => Token::Null,
+        "EMPTY" => Token::Empty,
+        _ => Token::Ident(word),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn check_depth(depth: usize) -> Result<(), NewsfreshError> {
+        if depth > MAX_DEPTH {
+            return Err(NewsfreshError::FilterExpression(format!(
+                "expression nesting exceeds the maximum depth of {MAX_DEPTH}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn match_token(&mut self, expected: &Token) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), NewsfreshError> {
+        if self.match_token(expected) {
+            Ok(())
+        } else {
+            Err(NewsfreshError::FilterExpression(format!(
+                "expected {expected:?}, found {:?}",
+                self.peek()
+            )))
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String, NewsfreshError> {
+        match self.advance() {
+            Some(Token::Ident(name)) => Ok(name),
+            other => Err(NewsfreshError::FilterExpression(format!(
+                "expected a field name, found {other:?}"
+            ))),
+        }
+    }
+
+    fn expect_value(&mut self) -> Result<String, NewsfreshError> {
+        match self.advance() {
+            Some(Token::Ident(v)) | Some(Token::Number(v)) | Some(Token::Str(v)) => Ok(v),
+            other => Err(NewsfreshError::FilterExpression(format!(
+                "expected a value, found {other:?}"
+            ))),
+        }
+    }
+
+    fn parse_or(&mut self, depth: usize) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+        Self::check_depth(depth)?;
+        let mut filters = vec![self.parse_and(depth + 1)?];
+        while self.match_token(&Token::Or) {
+            filters.push(self.parse_and(depth + 1)?);
+        }
+        Ok(if filters.len() == 1 {
+            filters.pop().unwrap()
+        } else {
+            Box::new(OrFilter(filters))
+        })
+    }
+
+    fn parse_and(&mut self, depth: usize) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+        Self::check_depth(depth)?;
+        let mut filters = vec![self.parse_not(depth + 1)?];
+        while self.match_token(&Token::And) {
+            filters.push(self.parse_not(depth + 1)?);
+        }
+        Ok(if filters.len() == 1 {
+            filters.pop().unwrap()
+        } else {
+            Box::new(AndFilter(filters))
+        })
+    }
+
+    fn parse_not(&mut self, depth: usize) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+        Self::check_depth(depth)?;
+        if self.match_token(&Token::Not) {
+            let inner = self.parse_not(depth + 1)?;
+            Ok(Box::new(NotFilter(inner)))
+        } else {
+            self.parse_primary(depth + 1)
+        }
+    }
+
+    fn parse_primary(&mut self, depth: usize) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+        Self::check_depth(depth)?;
+        if self.match_token(&Token::LParen) {
+            let inner = self.parse_or(depth + 1)?;
+            self.expect(&Token::RParen)?;
+            Ok(inner)
+        } else {
+            self.parse_comparison()
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+        let field = self.expect_ident()?;
+        let field = field.to_lowercase();
+
+        if self.match_token(&Token::Is) {
+            let negate = self.match_token(&Token::Not);
+            return match field.as_str() {
+                "image" => {
+                    self.expect(&Token::Null)?;
+                    let present: Box<dyn RecordFilter> = Box::new(HasImageFilter);
+                    Ok(if negate { present } else { Box::new(NotFilter(present)) })
+                }
+                "quotations" | "quotation" => {
+                    self.expect(&Token::Empty)?;
+                    let nonempty: Box<dyn RecordFilter> = Box::new(HasQuoteFilter);
+                    Ok(if negate { nonempty } else { Box::new(NotFilter(nonempty)) })
+                }
+                other => Err(NewsfreshError::FilterExpression(format!(
+                    "field '{other}' does not support IS NULL/IS EMPTY conditions"
+                ))),
+            };
+        }
+
+        let op = self.advance().ok_or_else(|| {
+            NewsfreshError::FilterExpression(format!("expected an operator after field '{field}'"))
+        })?;
+
+        match op {
+            Token::In => {
+                self.expect(&Token::LBracket)?;
+                let mut values = vec![self.expect_value()?];
+                while self.match_token(&Token::Comma) {
+                    values.push(self.expect_value()?);
+                }
+                self.expect(&Token::RBracket)?;
+                let alternatives = values
+                    .into_iter()
+                    .map(|v| build_leaf(&field, &Token::Eq, &v))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Box::new(OrFilter(alternatives)))
+            }
+            Token::Contains => {
+                let value = self.expect_value()?;
+                build_leaf(&field, &Token::Contains, &value)
+            }
+            Token::Ne => {
+                let value = self.expect_value()?;
+                Ok(Box::new(NotFilter(build_leaf(&field, &Token::Eq, &value)?)))
+            }
+            Token::Eq | Token::Gt | Token::Ge | Token::Lt | Token::Le => {
+                let value = self.expect_value()?;
+                build_leaf(&field, &op, &value)
+            }
+            other => Err(NewsfreshError::FilterExpression(format!(
+                "unexpected operator {other:?} for field '{field}'"
+            ))),
+        }
+    }
+}
+
+/// Maps one `field op value` leaf to the existing predicate struct for
+/// `field`, per-field restricting which operators make sense.
+fn build_leaf(field: &str, op: &Token, value: &str) -> Result<Box<dyn RecordFilter>, NewsfreshError> {
+    match field {
+        "person" | "persons" => Ok(Box::new(PersonFilter {
+            pattern: value.to_string(),
+            exact_accents: false,
+        })),
+        "org" | "organization" | "organizations" => Ok(Box::new(OrgFilter {
+            pattern: value.to_string(),
+            exact_accents: false,
+        })),
+        "theme" | "themes" => Ok(Box::new(ThemeFilter { pattern: value.to_string() })),
+        "theme_fuzzy" => Ok(Box::new(ThemeFuzzyMatchFilter { theme: value.to_string() })),
+        "location" | "locations" => Ok(Box::new(LocationFilter {
+            pattern: value.to_string(),
+            exact_accents: false,
+        })),
+        "country" => Ok(Box::new(CountryFilter { code: value.to_string() })),
+        "source" => Ok(Box::new(SourceFilter { pattern: value.to_string() })),
+        "tone" => {
+            let v: f64 = value.parse().map_err(|_| {
+                NewsfreshError::FilterExpression(format!("invalid numeric tone value '{value}'"))
+            })?;
+            match op {
+                Token::Gt => Ok(Box::new(ToneRangeFilter {
+                    min: Some(v),
+                    min_exclusive: true,
+                    ..Default::default()
+                })),
+                Token::Ge => Ok(Box::new(ToneRangeFilter { min: Some(v), ..Default::default() })),
+                Token::Lt => Ok(Box::new(ToneRangeFilter {
+                    max: Some(v),
+                    max_exclusive: true,
+                    ..Default::default()
+                })),
+                Token::Le => Ok(Box::new(ToneRangeFilter { max: Some(v), ..Default::default() })),
+                Token::Eq => {
+                    Ok(Box::new(ToneRangeFilter { min: Some(v), max: Some(v), ..Default::default() }))
+                }
+                _ => Err(NewsfreshError::FilterExpression(format!(
+                    "operator {op:?} is not supported for field 'tone'"
+                ))),
+            }
+        }
+        "date" => {
+            let v: i64 = value.parse().map_err(|_| {
+                NewsfreshError::FilterExpression(format!("invalid numeric date value '{value}'"))
+            })?;
+            match op {
+                Token::Gt => Ok(Box::new(DateRangeFilter {
+                    from: Some(v),
+                    from_exclusive: true,
+                    ..Default::default()
+                })),
+                Token::Ge => Ok(Box::new(DateRangeFilter { from: Some(v), ..Default::default() })),
+                Token::Lt => Ok(Box::new(DateRangeFilter {
+                    to: Some(v),
+                    to_exclusive: true,
+                    ..Default::default()
+                })),
+                Token::Le => Ok(Box::new(DateRangeFilter { to: Some(v), ..Default::default() })),
+                Token::Eq => {
+                    Ok(Box::new(DateRangeFilter { from: Some(v), to: Some(v), ..Default::default() }))
+                }
+                _ => Err(NewsfreshError::FilterExpression(format!(
+                    "operator {op:?} is not supported for field 'date'"
+                ))),
+            }
+        }
+        other => Err(NewsfreshError::FilterExpression(format!(
+            "unknown filter field '{other}'"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_test_record() -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "20250217-1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_persons: vec!["donald trump".into()],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec!["congress".into()],
+            v2_enhanced_organizations: vec![],
+            v1_themes: vec!["LEADER".into()],
+            v2_enhanced_themes: vec![EnhancedTheme { theme: "ELECTION".into(), char_offset: 50 }],
+            v1_locations: vec![LocationV1 {
+                location_type: 1,
+                full_name: "United States".into(),
+                country_code: "US".into(),
+                adm1_code: "US06".into(),
+                latitude: 38.0,
+                longitude: -97.0,
+                feature_id: "US".into(),
+            }],
+            v2_enhanced_locations: vec![],
+            tone: Some(Tone {
+                tone: -1.5,
+                positive_score: 2.0,
+                negative_score: 3.5,
+                polarity: 5.5,
+                activity_ref_density: 10.0,
+                self_group_ref_density: 0.5,
+                word_count: 500,
+            }),
+            quotations: vec![Quotation { offset: 10, length: 50, verb: "said".into(), quote: "test quote".into() }],
+            sharing_image: Some("https://img.example.com/photo.jpg".into()),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn simple_equality_leaf() {
+        let filter = parse_filter("theme = ELECTION").unwrap();
+        assert!(filter.matches(&make_test_record()));
+        let filter = parse_filter("theme = CLIMATE").unwrap();
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn and_combinator_requires_all() {
+        let filter = parse_filter("person = trump AND tone < 0").unwrap();
+        assert!(filter.matches(&make_test_record()));
+        let filter = parse_filter("person = trump AND tone > 0").unwrap();
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn or_combinator_requires_any() {
+        let filter = parse_filter("country IN [US, UK]").unwrap();
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn not_combinator_negates() {
+        let filter = parse_filter("NOT country = UK").unwrap();
+        assert!(filter.matches(&make_test_record()));
+        let filter = parse_filter("NOT country = US").unwrap();
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn parenthesized_grouping_controls_precedence() {
+        let filter = parse_filter("person = trump AND (country = UK OR country = US)").unwrap();
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn comparison_operators_on_tone() {
+        assert!(parse_filter("tone > -5").unwrap().matches(&make_test_record()));
+        assert!(parse_filter("tone <= 0").unwrap().matches(&make_test_record()));
+        assert!(!parse_filter("tone > 0").unwrap().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn comparison_operators_on_date() {
+        assert!(parse_filter("date >= 20250101000000").unwrap().matches(&make_test_record()));
+        assert!(!parse_filter("date >= 20260101000000").unwrap().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn gt_and_lt_are_exclusive_at_the_boundary_value() {
+        let record = make_test_record();
+        assert!(!parse_filter("tone > -1.5").unwrap().matches(&record));
+        assert!(parse_filter("tone >= -1.5").unwrap().matches(&record));
+        assert!(!parse_filter("tone < -1.5").unwrap().matches(&record));
+        assert!(parse_filter("tone <= -1.5").unwrap().matches(&record));
+
+        assert!(!parse_filter("date > 20250217120000").unwrap().matches(&record));
+        assert!(parse_filter("date >= 20250217120000").unwrap().matches(&record));
+        assert!(!parse_filter("date < 20250217120000").unwrap().matches(&record));
+        assert!(parse_filter("date <= 20250217120000").unwrap().matches(&record));
+    }
+
+    #[test]
+    fn contains_operator_on_source() {
+        assert!(parse_filter("source CONTAINS nytimes").unwrap().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn presence_conditions_on_image_and_quotations() {
+        assert!(parse_filter("image IS NOT NULL").unwrap().matches(&make_test_record()));
+        assert!(!parse_filter("image IS NULL").unwrap().matches(&make_test_record()));
+        assert!(parse_filter("quotations IS NOT EMPTY").unwrap().matches(&make_test_record()));
+        assert!(!parse_filter("quotations IS EMPTY").unwrap().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn not_equal_operator() {
+        let filter = parse_filter("country != US").unwrap();
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn theme_fuzzy_matches_transposed_theme() {
+        let filter = parse_filter("theme_fuzzy = elcetion").unwrap();
+        assert!(filter.matches(&make_test_record()));
+        let filter = parse_filter("theme_fuzzy = immigration").unwrap();
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn unknown_field_is_an_error() {
+        assert!(parse_filter("bogus = 1").is_err());
+    }
+
+    #[test]
+    fn unterminated_paren_is_an_error() {
+        assert!(parse_filter("(theme = ELECTION").is_err());
+    }
+
+    #[test]
+    fn excessive_nesting_is_rejected() {
+        let expr = format!("{}theme = ELECTION{}", "(".repeat(3000), ")".repeat(3000));
+        assert!(parse_filter(&expr).is_err());
+    }
+}
@@ -1,5 +1,8 @@
+pub mod expr;
 pub mod predicates;
 
+pub use expr::parse_filter;
+
 use crate::model::GkgRecord;
 
 pub trait RecordFilter: Send + Sync {
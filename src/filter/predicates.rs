@@ -1,39 +1,63 @@
+use serde::Deserialize;
+
+use crate::error::NewsfreshError;
 use crate::model::GkgRecord;
-use super::RecordFilter;
+use crate::parse::delimiters::normalize;
+use crate::parse::dates::to_date_range;
+use crate::search::fuzzy;
+use crate::search::themes;
+use super::expr::{NotFilter, OrFilter};
+use super::{CompositeFilter, RecordFilter};
+
+/// Mean Earth radius in kilometers, used for haversine great-circle distance.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Folds `s` to a comparable key: [`normalize`] (diacritic- and
+/// case-folding) unless `exact_accents` opts back into a plain
+/// `to_lowercase`, which still case-folds but preserves accented letters.
+fn fold(s: &str, exact_accents: bool) -> String {
+    if exact_accents {
+        s.to_lowercase()
+    } else {
+        normalize(s)
+    }
+}
 
 pub struct PersonFilter {
     pub pattern: String,
+    pub exact_accents: bool,
 }
 
 impl RecordFilter for PersonFilter {
     fn matches(&self, record: &GkgRecord) -> bool {
-        let pat = self.pattern.to_lowercase();
+        let pat = fold(&self.pattern, self.exact_accents);
         record
             .v1_persons
             .iter()
-            .any(|p| p.to_lowercase().contains(&pat))
+            .any(|p| fold(p, self.exact_accents).contains(&pat))
             || record
                 .v2_enhanced_persons
                 .iter()
-                .any(|p| p.name.to_lowercase().contains(&pat))
+                .any(|p| fold(&p.name, self.exact_accents).contains(&pat))
     }
 }
 
 pub struct OrgFilter {
     pub pattern: String,
+    pub exact_accents: bool,
 }
 
 impl RecordFilter for OrgFilter {
     fn matches(&self, record: &GkgRecord) -> bool {
-        let pat = self.pattern.to_lowercase();
+        let pat = fold(&self.pattern, self.exact_accents);
         record
             .v1_organizations
             .iter()
-            .any(|o| o.to_lowercase().contains(&pat))
+            .any(|o| fold(o, self.exact_accents).contains(&pat))
             || record
                 .v2_enhanced_organizations
                 .iter()
-                .any(|o| o.name.to_lowercase().contains(&pat))
+                .any(|o| fold(&o.name, self.exact_accents).contains(&pat))
     }
 }
 
@@ -54,19 +78,20 @@ impl RecordFilter for ThemeFilter {
 
 pub struct LocationFilter {
     pub pattern: String,
+    pub exact_accents: bool,
 }
 
 impl RecordFilter for LocationFilter {
     fn matches(&self, record: &GkgRecord) -> bool {
-        let pat = self.pattern.to_lowercase();
+        let pat = fold(&self.pattern, self.exact_accents);
         record
             .v1_locations
             .iter()
-            .any(|l| l.full_name.to_lowercase().contains(&pat))
+            .any(|l| fold(&l.full_name, self.exact_accents).contains(&pat))
             || record
                 .v2_enhanced_locations
                 .iter()
-                .any(|l| l.full_name.to_lowercase().contains(&pat))
+                .any(|l| fold(&l.full_name, self.exact_accents).contains(&pat))
     }
 }
 
@@ -88,9 +113,15 @@ impl RecordFilter for CountryFilter {
     }
 }
 
+/// Bounds a record's tone. `min`/`max` are inclusive by default; set
+/// `min_exclusive`/`max_exclusive` to make the corresponding bound strict
+/// (e.g. the filter expression operator `>` rather than `>=`).
+#[derive(Default)]
 pub struct ToneRangeFilter {
     pub min: Option<f64>,
     pub max: Option<f64>,
+    pub min_exclusive: bool,
+    pub max_exclusive: bool,
 }
 
 impl RecordFilter for ToneRangeFilter {
@@ -99,12 +130,12 @@ impl RecordFilter for ToneRangeFilter {
             return false;
         };
         if let Some(min) = self.min {
-            if tone.tone < min {
+            if if self.min_exclusive { tone.tone <= min } else { tone.tone < min } {
                 return false;
             }
         }
         if let Some(max) = self.max {
-            if tone.tone > max {
+            if if self.max_exclusive { tone.tone >= max } else { tone.tone > max } {
                 return false;
             }
         }
@@ -112,20 +143,26 @@ impl RecordFilter for ToneRangeFilter {
     }
 }
 
+/// Bounds a record's date. `from`/`to` are inclusive by default; set
+/// `from_exclusive`/`to_exclusive` to make the corresponding bound strict
+/// (e.g. the filter expression operator `>` rather than `>=`).
+#[derive(Default)]
 pub struct DateRangeFilter {
     pub from: Option<i64>,
     pub to: Option<i64>,
+    pub from_exclusive: bool,
+    pub to_exclusive: bool,
 }
 
 impl RecordFilter for DateRangeFilter {
     fn matches(&self, record: &GkgRecord) -> bool {
         if let Some(from) = self.from {
-            if record.date < from {
+            if if self.from_exclusive { record.date <= from } else { record.date < from } {
                 return false;
             }
         }
         if let Some(to) = self.to {
-            if record.date > to {
+            if if self.to_exclusive { record.date >= to } else { record.date > to } {
                 return false;
             }
         }
@@ -162,6 +199,301 @@ impl RecordFilter for HasQuoteFilter {
     }
 }
 
+pub struct ThemeEqualsFilter {
+    pub theme: String,
+}
+
+impl RecordFilter for ThemeEqualsFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        let pat = self.theme.to_uppercase();
+        record
+            .v1_themes
+            .iter()
+            .any(|t| t.to_uppercase() == pat || themes::canonicalize_theme(t).to_uppercase() == pat)
+            || record.v2_enhanced_themes.iter().any(|t| {
+                t.theme.to_uppercase() == pat
+                    || themes::canonicalize_theme(&t.theme).to_uppercase() == pat
+            })
+    }
+}
+
+pub struct ThemeContainsFilter {
+    pub theme: String,
+}
+
+impl RecordFilter for ThemeContainsFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        let pat = self.theme.to_uppercase();
+        record.v1_themes.iter().any(|t| {
+            t.to_uppercase().contains(&pat) || themes::canonicalize_theme(t).to_uppercase().contains(&pat)
+        }) || record.v2_enhanced_themes.iter().any(|t| {
+            t.theme.to_uppercase().contains(&pat)
+                || themes::canonicalize_theme(&t.theme).to_uppercase().contains(&pat)
+        })
+    }
+}
+
+/// Matches a theme within a typo tolerance derived from the query's own
+/// length (see [`fuzzy::token_match_budget`]), so e.g. `TAX_ETHNICITY_TAMIL`
+/// still matches a query for "tamil" with a transposed letter — GKG theme
+/// vocabularies are large and users rarely type the codes exactly.
+pub struct ThemeFuzzyMatchFilter {
+    pub theme: String,
+}
+
+impl RecordFilter for ThemeFuzzyMatchFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        let needle = self.theme.to_uppercase();
+        let budget = fuzzy::token_match_budget(needle.chars().count());
+        record.v1_themes.iter().any(|t| {
+            fuzzy::fuzzy_contains(&t.to_uppercase(), &needle, budget)
+                || fuzzy::fuzzy_contains(&themes::canonicalize_theme(t).to_uppercase(), &needle, budget)
+        }) || record.v2_enhanced_themes.iter().any(|t| {
+            fuzzy::fuzzy_contains(&t.theme.to_uppercase(), &needle, budget)
+                || fuzzy::fuzzy_contains(
+                    &themes::canonicalize_theme(&t.theme).to_uppercase(),
+                    &needle,
+                    budget,
+                )
+        })
+    }
+}
+
+pub struct PersonEqualsFilter {
+    pub person: String,
+}
+
+impl RecordFilter for PersonEqualsFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        let pat = fold(&self.person, false);
+        record.v1_persons.iter().any(|p| fold(p, false) == pat)
+            || record.v2_enhanced_persons.iter().any(|p| fold(&p.name, false) == pat)
+    }
+}
+
+pub struct OrganizationEqualsFilter {
+    pub organization: String,
+}
+
+impl RecordFilter for OrganizationEqualsFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        let pat = fold(&self.organization, false);
+        record.v1_organizations.iter().any(|o| fold(o, false) == pat)
+            || record.v2_enhanced_organizations.iter().any(|o| fold(&o.name, false) == pat)
+    }
+}
+
+/// Matches records with at least one `v21_enhanced_dates` entry whose
+/// normalized `[earliest, latest]` interval (see [`to_date_range`]) overlaps
+/// the `[from, to]` window, rather than requiring an exact day match — a
+/// year- or month-resolution mention overlaps the window if any day in its
+/// range falls inside it.
+pub struct MentionedDateInRangeFilter {
+    pub from: i64,
+    pub to: i64,
+}
+
+impl RecordFilter for MentionedDateInRangeFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        record.v21_enhanced_dates.iter().any(|d| {
+            to_date_range(d).is_some_and(|(earliest, latest)| earliest <= self.to && latest >= self.from)
+        })
+    }
+}
+
+pub struct SourceCommonNameEqualsFilter {
+    pub source: String,
+}
+
+impl RecordFilter for SourceCommonNameEqualsFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        record.source_common_name.to_lowercase() == self.source.to_lowercase()
+    }
+}
+
+/// A declarative, (de)serializable filter predicate tree — the JSON/YAML
+/// counterpart to [`super::expr::parse_filter`]'s boolean text DSL. Builds
+/// the same `Box<dyn RecordFilter>` combinators as the text DSL, so filter
+/// configs can ship as data (a request body, a saved config file) without a
+/// recompile.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Predicate {
+    AllOf { filters: Vec<Predicate> },
+    AnyOf { filters: Vec<Predicate> },
+    Not { filter: Box<Predicate> },
+    ThemeEquals { theme: String },
+    ThemeContains { theme: String },
+    ThemeFuzzyMatch { theme: String },
+    PersonEquals { person: String },
+    OrganizationEquals { organization: String },
+    LocationCountryEquals { country: String },
+    ToneAbove { tone: f32 },
+    ToneBelow { tone: f32 },
+    SourceCommonNameEquals { source: String },
+    DateInRange {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
+    MentionedDateInRange {
+        from: i64,
+        to: i64,
+    },
+}
+
+impl Predicate {
+    /// Compiles this predicate tree into a boxed [`RecordFilter`], ready to
+    /// hand to a [`CompositeFilter`] or use standalone. `AllOf` reuses
+    /// `CompositeFilter`'s existing all-must-match semantics; `AnyOf` and
+    /// `Not` reuse the OR/NOT combinators from the text filter DSL.
+    pub fn compile(self) -> Box<dyn RecordFilter> {
+        match self {
+            Predicate::AllOf { filters } => {
+                let mut composite = CompositeFilter::new();
+                for f in filters {
+                    composite.add(f.compile());
+                }
+                Box::new(composite)
+            }
+            Predicate::AnyOf { filters } => {
+                Box::new(OrFilter(filters.into_iter().map(Predicate::compile).collect()))
+            }
+            Predicate::Not { filter } => Box::new(NotFilter(filter.compile())),
+            Predicate::ThemeEquals { theme } => Box::new(ThemeEqualsFilter { theme }),
+            Predicate::ThemeContains { theme } => Box::new(ThemeContainsFilter { theme }),
+            Predicate::ThemeFuzzyMatch { theme } => Box::new(ThemeFuzzyMatchFilter { theme }),
+            Predicate::PersonEquals { person } => Box::new(PersonEqualsFilter { person }),
+            Predicate::OrganizationEquals { organization } => {
+                Box::new(OrganizationEqualsFilter { organization })
+            }
+            Predicate::LocationCountryEquals { country } => {
+                Box::new(CountryFilter { code: country })
+            }
+            Predicate::ToneAbove { tone } => Box::new(ToneRangeFilter {
+                min: Some(tone as f64),
+                ..Default::default()
+            }),
+            Predicate::ToneBelow { tone } => Box::new(ToneRangeFilter {
+                max: Some(tone as f64),
+                ..Default::default()
+            }),
+            Predicate::SourceCommonNameEquals { source } => {
+                Box::new(SourceCommonNameEqualsFilter { source })
+            }
+            Predicate::DateInRange { start, end } => {
+                Box::new(DateRangeFilter { from: start, to: end, ..Default::default() })
+            }
+            Predicate::MentionedDateInRange { from, to } => {
+                Box::new(MentionedDateInRangeFilter { from, to })
+            }
+        }
+    }
+}
+
+/// A coordinate pair that looks like an unparsed/missing location rather
+/// than a real one: `(0.0, 0.0)` with no `feature_id` to back it up.
+fn is_unparsed_coordinate(lat: f64, lon: f64, feature_id: &str) -> bool {
+    lat == 0.0 && lon == 0.0 && feature_id.is_empty()
+}
+
+/// Matches records with at least one location within `radius_km` of
+/// `(lat, lon)`, using haversine great-circle distance.
+pub struct GeoRadiusFilter {
+    pub lat: f64,
+    pub lon: f64,
+    pub radius_km: f64,
+}
+
+impl GeoRadiusFilter {
+    fn haversine_distance_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+        let (lat1, lon1, lat2, lon2) = (
+            lat1.to_radians(),
+            lon1.to_radians(),
+            lat2.to_radians(),
+            lon2.to_radians(),
+        );
+        let dlat = lat2 - lat1;
+        let dlon = lon2 - lon1;
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
+        EARTH_RADIUS_KM * c
+    }
+}
+
+impl RecordFilter for GeoRadiusFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        record
+            .v1_locations
+            .iter()
+            .map(|l| (l.latitude, l.longitude, l.feature_id.as_str()))
+            .chain(
+                record
+                    .v2_enhanced_locations
+                    .iter()
+                    .map(|l| (l.latitude, l.longitude, l.feature_id.as_str())),
+            )
+            .filter(|&(lat, lon, feature_id)| !is_unparsed_coordinate(lat, lon, feature_id))
+            .any(|(lat, lon, _)| {
+                Self::haversine_distance_km(self.lat, self.lon, lat, lon) <= self.radius_km
+            })
+    }
+}
+
+/// Matches records with at least one location inside a lat/lon bounding
+/// box. `left_lon > right_lon` is treated as a box that crosses the
+/// antimeridian, wrapping the longitude test instead of rejecting it.
+pub struct GeoBoundingBoxFilter {
+    pub top_lat: f64,
+    pub bottom_lat: f64,
+    pub left_lon: f64,
+    pub right_lon: f64,
+}
+
+impl GeoBoundingBoxFilter {
+    pub fn new(
+        top_lat: f64,
+        bottom_lat: f64,
+        left_lon: f64,
+        right_lon: f64,
+    ) -> Result<Self, NewsfreshError> {
+        if top_lat < bottom_lat {
+            return Err(NewsfreshError::FilterExpression(format!(
+                "invalid bounding box: top_lat ({top_lat}) must be >= bottom_lat ({bottom_lat})"
+            )));
+        }
+        Ok(Self { top_lat, bottom_lat, left_lon, right_lon })
+    }
+
+    fn contains(&self, lat: f64, lon: f64) -> bool {
+        if lat > self.top_lat || lat < self.bottom_lat {
+            return false;
+        }
+        if self.left_lon <= self.right_lon {
+            lon >= self.left_lon && lon <= self.right_lon
+        } else {
+            // Antimeridian-crossing box: the valid range wraps around ±180.
+            lon >= self.left_lon || lon <= self.right_lon
+        }
+    }
+}
+
+impl RecordFilter for GeoBoundingBoxFilter {
+    fn matches(&self, record: &GkgRecord) -> bool {
+        record
+            .v1_locations
+            .iter()
+            .map(|l| (l.latitude, l.longitude, l.feature_id.as_str()))
+            .chain(
+                record
+                    .v2_enhanced_locations
+                    .iter()
+                    .map(|l| (l.latitude, l.longitude, l.feature_id.as_str())),
+            )
+            .filter(|&(lat, lon, feature_id)| !is_unparsed_coordinate(lat, lon, feature_id))
+            .any(|(lat, lon, _)| self.contains(lat, lon))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,27 +532,43 @@ mod tests {
 
     #[test]
     fn person_filter_matches_case_insensitive() {
-        let filter = PersonFilter { pattern: "trump".into() };
+        let filter = PersonFilter { pattern: "trump".into(), exact_accents: false };
         assert!(filter.matches(&make_test_record()));
     }
 
     #[test]
     fn person_filter_no_match() {
-        let filter = PersonFilter { pattern: "obama".into() };
+        let filter = PersonFilter { pattern: "obama".into(), exact_accents: false };
         assert!(!filter.matches(&make_test_record()));
     }
 
+    #[test]
+    fn person_filter_folds_diacritics_by_default() {
+        let filter = PersonFilter { pattern: "jose".into(), exact_accents: false };
+        let mut record = make_test_record();
+        record.v1_persons = vec!["José Martínez".into()];
+        assert!(filter.matches(&record));
+    }
+
+    #[test]
+    fn person_filter_exact_accents_requires_precise_match() {
+        let filter = PersonFilter { pattern: "jose".into(), exact_accents: true };
+        let mut record = make_test_record();
+        record.v1_persons = vec!["José Martínez".into()];
+        assert!(!filter.matches(&record));
+    }
+
     // ---- OrgFilter ----
 
     #[test]
     fn org_filter_matches() {
-        let filter = OrgFilter { pattern: "congress".into() };
+        let filter = OrgFilter { pattern: "congress".into(), exact_accents: false };
         assert!(filter.matches(&make_test_record()));
     }
 
     #[test]
     fn org_filter_no_match() {
-        let filter = OrgFilter { pattern: "pentagon".into() };
+        let filter = OrgFilter { pattern: "pentagon".into(), exact_accents: false };
         assert!(!filter.matches(&make_test_record()));
     }
 
@@ -242,16 +590,24 @@ mod tests {
 
     #[test]
     fn location_filter_matches() {
-        let filter = LocationFilter { pattern: "United States".into() };
+        let filter = LocationFilter { pattern: "United States".into(), exact_accents: false };
         assert!(filter.matches(&make_test_record()));
     }
 
     #[test]
     fn location_filter_no_match() {
-        let filter = LocationFilter { pattern: "London".into() };
+        let filter = LocationFilter { pattern: "London".into(), exact_accents: false };
         assert!(!filter.matches(&make_test_record()));
     }
 
+    #[test]
+    fn location_filter_folds_diacritics_by_default() {
+        let filter = LocationFilter { pattern: "zurich".into(), exact_accents: false };
+        let mut record = make_test_record();
+        record.v1_locations[0].full_name = "Zürich".into();
+        assert!(filter.matches(&record));
+    }
+
     // ---- CountryFilter ----
 
     #[test]
@@ -270,19 +626,19 @@ mod tests {
 
     #[test]
     fn tone_range_filter_matches_in_range() {
-        let filter = ToneRangeFilter { min: Some(-5.0), max: Some(0.0) };
+        let filter = ToneRangeFilter { min: Some(-5.0), max: Some(0.0), ..Default::default() };
         assert!(filter.matches(&make_test_record()));
     }
 
     #[test]
     fn tone_range_filter_no_match_out_of_range() {
-        let filter = ToneRangeFilter { min: Some(0.0), max: Some(5.0) };
+        let filter = ToneRangeFilter { min: Some(0.0), max: Some(5.0), ..Default::default() };
         assert!(!filter.matches(&make_test_record()));
     }
 
     #[test]
     fn tone_range_filter_no_tone_returns_false() {
-        let filter = ToneRangeFilter { min: Some(-5.0), max: Some(0.0) };
+        let filter = ToneRangeFilter { min: Some(-5.0), max: Some(0.0), ..Default::default() };
         let mut record = make_test_record();
         record.tone = None;
         assert!(!filter.matches(&record));
@@ -292,16 +648,45 @@ mod tests {
 
     #[test]
     fn date_range_filter_matches_in_range() {
-        let filter = DateRangeFilter { from: Some(20250101000000), to: Some(20250301000000) };
+        let filter = DateRangeFilter { from: Some(20250101000000), to: Some(20250301000000), ..Default::default() };
         assert!(filter.matches(&make_test_record()));
     }
 
     #[test]
     fn date_range_filter_no_match_out_of_range() {
-        let filter = DateRangeFilter { from: Some(20250301000000), to: None };
+        let filter = DateRangeFilter { from: Some(20250301000000), to: None, ..Default::default() };
         assert!(!filter.matches(&make_test_record()));
     }
 
+    // ---- MentionedDateInRangeFilter ----
+
+    #[test]
+    fn mentioned_date_in_range_filter_matches_overlapping_month_resolution() {
+        let mut record = make_test_record();
+        record.v21_enhanced_dates =
+            vec![EnhancedDate { resolution: 2, month: 6, day: 0, year: 2023, char_offset: 0 }];
+        let filter = MentionedDateInRangeFilter { from: 20230615, to: 20230701 };
+        assert!(filter.matches(&record));
+    }
+
+    #[test]
+    fn mentioned_date_in_range_filter_no_match_outside_window() {
+        let mut record = make_test_record();
+        record.v21_enhanced_dates =
+            vec![EnhancedDate { resolution: 1, month: 3, day: 15, year: 2024, char_offset: 0 }];
+        let filter = MentionedDateInRangeFilter { from: 20240101, to: 20240201 };
+        assert!(!filter.matches(&record));
+    }
+
+    #[test]
+    fn mentioned_date_in_range_filter_ignores_unparseable_dates() {
+        let mut record = make_test_record();
+        record.v21_enhanced_dates =
+            vec![EnhancedDate { resolution: 1, month: 0, day: 0, year: 0, char_offset: 0 }];
+        let filter = MentionedDateInRangeFilter { from: 20200101, to: 20251231 };
+        assert!(!filter.matches(&record));
+    }
+
     // ---- SourceFilter ----
 
     #[test]
@@ -347,4 +732,229 @@ mod tests {
         record.quotations = vec![];
         assert!(!filter.matches(&record));
     }
+
+    // ---- GeoRadiusFilter ----
+
+    #[test]
+    fn geo_radius_filter_matches_within_radius() {
+        // Record location is (38.0, -97.0); Wichita, KS is close by.
+        let filter = GeoRadiusFilter { lat: 37.69, lon: -97.34, radius_km: 100.0 };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn geo_radius_filter_no_match_outside_radius() {
+        // London is thousands of km from the fixture's US location.
+        let filter = GeoRadiusFilter { lat: 51.5, lon: -0.13, radius_km: 100.0 };
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn geo_radius_filter_skips_unparsed_coordinates() {
+        let filter = GeoRadiusFilter { lat: 0.0, lon: 0.0, radius_km: 10.0 };
+        let mut record = make_test_record();
+        record.v1_locations = vec![LocationV1 {
+            location_type: 1, full_name: "Unknown".into(),
+            country_code: "".into(), adm1_code: "".into(),
+            latitude: 0.0, longitude: 0.0, feature_id: "".into(),
+        }];
+        assert!(!filter.matches(&record));
+    }
+
+    // ---- GeoBoundingBoxFilter ----
+
+    #[test]
+    fn geo_bounding_box_filter_matches_inside_box() {
+        let filter = GeoBoundingBoxFilter::new(45.0, 25.0, -110.0, -80.0).unwrap();
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_no_match_outside_box() {
+        let filter = GeoBoundingBoxFilter::new(60.0, 50.0, -10.0, 10.0).unwrap();
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_rejects_inverted_latitudes() {
+        let result = GeoBoundingBoxFilter::new(25.0, 45.0, -110.0, -80.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_handles_antimeridian_crossing() {
+        // Box spans from 170E to -170E (crossing the antimeridian).
+        let filter = GeoBoundingBoxFilter::new(10.0, -10.0, 170.0, -170.0).unwrap();
+        let mut record = make_test_record();
+        record.v1_locations = vec![LocationV1 {
+            location_type: 1, full_name: "Fiji".into(),
+            country_code: "FJ".into(), adm1_code: "".into(),
+            latitude: 0.0, longitude: 179.5, feature_id: "FJ".into(),
+        }];
+        assert!(filter.matches(&record));
+    }
+
+    #[test]
+    fn geo_bounding_box_filter_skips_unparsed_coordinates() {
+        let filter = GeoBoundingBoxFilter::new(10.0, -10.0, -10.0, 10.0).unwrap();
+        let mut record = make_test_record();
+        record.v1_locations = vec![LocationV1 {
+            location_type: 1, full_name: "Unknown".into(),
+            country_code: "".into(), adm1_code: "".into(),
+            latitude: 0.0, longitude: 0.0, feature_id: "".into(),
+        }];
+        assert!(!filter.matches(&record));
+    }
+
+    // ---- ThemeEqualsFilter / ThemeContainsFilter ----
+
+    #[test]
+    fn theme_equals_filter_matches_exact_v1_theme() {
+        let filter = ThemeEqualsFilter { theme: "leader".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn theme_equals_filter_no_match_on_partial_theme() {
+        let filter = ThemeEqualsFilter { theme: "LEAD".into() };
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn theme_contains_filter_matches_partial_theme() {
+        let filter = ThemeContainsFilter { theme: "fncact".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    // ---- ThemeFuzzyMatchFilter ----
+
+    #[test]
+    fn theme_fuzzy_match_filter_matches_transposed_v1_theme() {
+        let filter = ThemeFuzzyMatchFilter { theme: "presdient".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn theme_fuzzy_match_filter_matches_misspelled_v2_theme() {
+        let filter = ThemeFuzzyMatchFilter { theme: "electoon".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn theme_fuzzy_match_filter_no_match_beyond_budget() {
+        let filter = ThemeFuzzyMatchFilter { theme: "immigration".into() };
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    // ---- PersonEqualsFilter / OrganizationEqualsFilter ----
+
+    #[test]
+    fn person_equals_filter_matches_exact_name() {
+        let filter = PersonEqualsFilter { person: "donald trump".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn person_equals_filter_no_match_on_partial_name() {
+        let filter = PersonEqualsFilter { person: "trump".into() };
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn organization_equals_filter_matches_exact_name() {
+        let filter = OrganizationEqualsFilter { organization: "congress".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    // ---- SourceCommonNameEqualsFilter ----
+
+    #[test]
+    fn source_common_name_equals_filter_matches_exact_name() {
+        let filter = SourceCommonNameEqualsFilter { source: "nytimes.com".into() };
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn source_common_name_equals_filter_no_match_on_partial_name() {
+        let filter = SourceCommonNameEqualsFilter { source: "nytimes".into() };
+        assert!(!filter.matches(&make_test_record()));
+    }
+
+    // ---- Predicate ----
+
+    #[test]
+    fn predicate_deserializes_and_compiles_leaf() {
+        let json = r#"{"op": "person_equals", "person": "donald trump"}"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        let filter = predicate.compile();
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn predicate_theme_fuzzy_match_deserializes_and_compiles() {
+        let json = r#"{"op": "theme_fuzzy_match", "theme": "presdient"}"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        let filter = predicate.compile();
+        assert!(filter.matches(&make_test_record()));
+    }
+
+    #[test]
+    fn predicate_mentioned_date_in_range_deserializes_and_compiles() {
+        let mut record = make_test_record();
+        record.v21_enhanced_dates =
+            vec![EnhancedDate { resolution: 3, month: 0, day: 0, year: 2023, char_offset: 0 }];
+        let json = r#"{"op": "mentioned_date_in_range", "from": 20230101, "to": 20231231}"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        let filter = predicate.compile();
+        assert!(filter.matches(&record));
+    }
+
+    #[test]
+    fn predicate_all_of_requires_every_child_to_match() {
+        let json = r#"{
+            "op": "all_of",
+            "filters": [
+                {"op": "person_equals", "person": "donald trump"},
+                {"op": "location_country_equals", "country": "UK"}
+            ]
+        }"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        assert!(!predicate.compile().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn predicate_any_of_matches_if_one_child_matches() {
+        let json = r#"{
+            "op": "any_of",
+            "filters": [
+                {"op": "person_equals", "person": "donald trump"},
+                {"op": "location_country_equals", "country": "UK"}
+            ]
+        }"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        assert!(predicate.compile().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn predicate_not_negates_child() {
+        let json = r#"{
+            "op": "not",
+            "filter": {"op": "location_country_equals", "country": "UK"}
+        }"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        assert!(predicate.compile().matches(&make_test_record()));
+    }
+
+    #[test]
+    fn predicate_tone_above_and_date_in_range_compile() {
+        let json = r#"{
+            "op": "all_of",
+            "filters": [
+                {"op": "tone_above", "tone": -10.0},
+                {"op": "date_in_range", "start": 20250101000000, "end": 20250301000000}
+            ]
+        }"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+        assert!(predicate.compile().matches(&make_test_record()));
+    }
 }
@@ -91,14 +91,14 @@
 //! // Apply filters
 //! let mut filters = CompositeFilter::new();
 //! filters.add(Box::new(CountryFilter { code: "US".to_string() }));
-//! filters.add(Box::new(PersonFilter { pattern: "Trump".to_string() }));
+//! filters.add(Box::new(PersonFilter { pattern: "Trump".to_string(), exact_accents: false }));
 //!
 //! let filtered: Vec<_> = records.iter()
 //!     .filter(|r| filters.matches(r))
 //!     .collect();
 //!
 //! // Full-text search
-//! let mut engine = search::create_engine();
+//! let mut engine = search::create_engine("tantivy");
 //! engine.build(&records).unwrap();
 //! let hits = engine.search("climate policy carbon", 20).unwrap();
 //! // hits[i].record_index, hits[i].score
@@ -202,11 +202,16 @@
 //! The `lastupdate.txt` file lists the latest 15-minute update files.
 //! Historical files are available by date in `YYYYMMDDHHMMSS` format.
 
+pub mod aggregate;
 pub mod cli;
+pub mod embed;
 pub mod error;
+#[cfg(feature = "sqlite")]
+pub mod export;
 pub mod fetch;
 pub mod filter;
 pub mod model;
+pub mod nlp;
 pub mod output;
 pub mod parse;
 pub mod search;
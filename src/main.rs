@@ -2,14 +2,23 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 
 use clap::Parser;
+use futures_util::stream::{self, StreamExt};
 use tracing::{debug, warn};
 
+use newsfresh::aggregate;
 use newsfresh::cli::*;
+use newsfresh::embed;
 use newsfresh::error::NewsfreshError;
-use newsfresh::fetch::{client, decompress, lastupdate};
+use newsfresh::fetch::cache::HttpCache;
+use newsfresh::fetch::client::ClientConfig;
+use newsfresh::fetch::download_cache::DownloadCache;
+use newsfresh::fetch::lastupdate::LastUpdateEntry;
+use newsfresh::fetch::{client, decompress, lastupdate, range, verify};
+use newsfresh::filter;
 use newsfresh::filter::predicates::*;
 use newsfresh::filter::{CompositeFilter, RecordFilter};
-use newsfresh::model::ScoredRecord;
+use newsfresh::model::{GkgRecord, ScoreDetail, ScoredRecord};
+use newsfresh::nlp::source_language::backfill_translation_info;
 use newsfresh::output::{self, OutputFormatter};
 use newsfresh::parse;
 use newsfresh::search;
@@ -32,33 +41,77 @@ async fn main() -> Result<(), NewsfreshError> {
         .with_writer(std::io::stderr)
         .init();
 
+    let client_config = ClientConfig { insecure: cli.insecure };
+
     match cli.command {
-        Command::Fetch(args) => cmd_fetch(args).await,
+        Command::Fetch(args) => cmd_fetch(args, &client_config).await,
         Command::Parse(args) => cmd_parse(args),
-        Command::Query(args) => cmd_query(args).await,
+        Command::Query(args) => cmd_query(args, &client_config).await,
         Command::Schema(args) => cmd_schema(args),
-        Command::Analyze(args) => cmd_analyze(args).await,
+        Command::Analyze(args) => cmd_analyze(args, &client_config).await,
+        Command::Watch(args) => cmd_watch(args, client_config).await,
     }
 }
 
-async fn cmd_fetch(args: FetchArgs) -> Result<(), NewsfreshError> {
+async fn cmd_fetch(args: FetchArgs, client_config: &ClientConfig) -> Result<(), NewsfreshError> {
     std::fs::create_dir_all(&args.output)?;
 
-    let url = if let Some(ref date) = args.date {
-        client::historical_url(date)
+    if let (Some(from), Some(to)) = (&args.range.from, &args.range.to) {
+        let timestamps = range::enumerate_slices(from, to)?;
+        eprintln!("Fetching {} slice(s) from {from} to {to}", timestamps.len());
+        let csv_paths = fetch_gkg_range(
+            &timestamps,
+            &args.output,
+            args.range.max_concurrency,
+            client_config,
+            args.keep_zip,
+        )
+        .await?;
+        eprintln!("Extracted {} of {} slice(s)", csv_paths.len(), timestamps.len());
+        return Ok(());
+    }
+
+    let cache_ttl = args.cache.cache_ttl.map(|m| std::time::Duration::from_secs(m * 60));
+    let mut http_cache = open_http_cache(&args.cache)?;
+
+    let entry = if args.date.is_some() {
+        None
     } else {
-        let text = client::fetch_text(client::lastupdate_url(args.translation)).await?;
+        let text = http_cache
+            .fetch_text(
+                client::lastupdate_url(args.translation),
+                client_config,
+                cache_ttl,
+                args.cache.no_cache,
+            )
+            .await?;
         let entries = lastupdate::parse_lastupdate(&text);
-        lastupdate::find_gkg_url(&entries)?
+        Some(lastupdate::find_gkg_entry(&entries)?)
+    };
+    let url = match (&args.date, &entry) {
+        (Some(date), _) => client::historical_url(date),
+        (None, Some(entry)) => entry.url.clone(),
+        (None, None) => unreachable!("entry is always Some when date is None"),
     };
 
     let filename = url.rsplit('/').next().unwrap_or("gkg.csv.zip");
     let zip_path = args.output.join(filename);
 
-    eprintln!("Fetching: {url}");
-    client::download_file(&url, &zip_path).await?;
+    let mut cache = open_download_cache(&args.cache)?;
+    fetch_gkg_archive(
+        &url,
+        entry.as_ref(),
+        &zip_path,
+        client_config,
+        cache.as_mut(),
+        &mut http_cache,
+        cache_ttl,
+        args.cache.no_cache,
+    )
+    .await?;
 
-    let csv_path = decompress::extract_gkg_from_zip(&zip_path, &args.output)?;
+    let manifest = decompress::extract_gkg_from_zip_verified(&zip_path, &args.output, None)?;
+    let csv_path = args.output.join(&manifest.file_name);
     eprintln!("Extracted: {}", csv_path.display());
 
     if !args.keep_zip {
@@ -69,53 +122,156 @@ async fn cmd_fetch(args: FetchArgs) -> Result<(), NewsfreshError> {
 }
 
 fn cmd_parse(args: ParseArgs) -> Result<(), NewsfreshError> {
-    let filters = build_filters(&args.filter);
+    let filters = build_filters(&args.filter)?;
     let writer = make_writer(&args.output.output)?;
     let format_str = format_to_str(&args.output.format);
-    let mut formatter = output::create_formatter(&format_str, writer, &args.output.fields);
+    let mut formatter = output::create_formatter(
+        &format_str,
+        writer,
+        &args.output.fields,
+        args.output.facet_limit,
+        args.output.output.as_deref(),
+    );
+
+    if let Some(ref dir) = args.dir {
+        return run_multi_pipeline(dir, &filters, &mut *formatter, args.output.offset, args.output.limit);
+    }
 
-    let reader = open_gkg_file(&args.file)?;
     run_pipeline(
-        reader,
+        &[args.file.expect("clap requires --file when --dir is absent")],
         &filters,
         &mut *formatter,
         args.output.offset,
         args.output.limit,
+        None,
     )
 }
 
-async fn cmd_query(args: QueryArgs) -> Result<(), NewsfreshError> {
+/// Like [`run_pipeline`], but streams every record reachable from `root` (a
+/// directory, a multi-entry `.zip`, or a bare `.csv`) via
+/// [`parse::MultiGkgReader`] in tolerant mode instead of opening each path's
+/// lines directly — the entry point for `parse --dir`.
+fn run_multi_pipeline(
+    root: &Path,
+    filters: &CompositeFilter,
+    formatter: &mut dyn OutputFormatter,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<(), NewsfreshError> {
+    formatter.begin()?;
+
+    let mut count: usize = 0;
+    let mut skipped: usize = 0;
+    let skip_n = offset.unwrap_or(0);
+
+    let mut reader = parse::MultiGkgReader::open(root)?.tolerant();
+    for result in reader.by_ref() {
+        let record = result?;
+        if !filters.matches(&record) {
+            continue;
+        }
+        if skipped < skip_n {
+            skipped += 1;
+            continue;
+        }
+        formatter.write_record(&record)?;
+        count += 1;
+        if let Some(lim) = limit
+            && count >= lim
+        {
+            break;
+        }
+    }
+
+    formatter.finish()?;
+
+    eprintln!(
+        "Output {count} records ({} malformed lines skipped)",
+        reader.malformed_lines().len()
+    );
+    Ok(())
+}
+
+async fn cmd_query(args: QueryArgs, client_config: &ClientConfig) -> Result<(), NewsfreshError> {
     let data_dir = resolve_data_dir(args.persist_data_file)?;
 
-    let url = if let Some(ref date) = args.date {
-        client::historical_url(date)
+    let csv_paths = if let (Some(from), Some(to)) = (&args.range.from, &args.range.to) {
+        let timestamps = range::enumerate_slices(from, to)?;
+        eprintln!("Fetching {} slice(s) from {from} to {to}", timestamps.len());
+        let paths = fetch_gkg_range(
+            &timestamps,
+            data_dir.path(),
+            args.range.max_concurrency,
+            client_config,
+            true,
+        )
+        .await?;
+        eprintln!("Parsing {} of {} slice(s)", paths.len(), timestamps.len());
+        paths
     } else {
-        let text = client::fetch_text(client::lastupdate_url(args.translation)).await?;
-        let entries = lastupdate::parse_lastupdate(&text);
-        lastupdate::find_gkg_url(&entries)?
-    };
+        let cache_ttl = args.cache.cache_ttl.map(|m| std::time::Duration::from_secs(m * 60));
+        let mut http_cache = open_http_cache(&args.cache)?;
 
-    let filename = url.rsplit('/').next().unwrap_or("gkg.csv.zip");
-    let zip_path = data_dir.path().join(filename);
+        let entry = if args.date.is_some() {
+            None
+        } else {
+            let text = http_cache
+                .fetch_text(
+                    client::lastupdate_url(args.translation),
+                    client_config,
+                    cache_ttl,
+                    args.cache.no_cache,
+                )
+                .await?;
+            let entries = lastupdate::parse_lastupdate(&text);
+            Some(lastupdate::find_gkg_entry(&entries)?)
+        };
+        let url = match (&args.date, &entry) {
+            (Some(date), _) => client::historical_url(date),
+            (None, Some(entry)) => entry.url.clone(),
+            (None, None) => unreachable!("entry is always Some when date is None"),
+        };
 
-    eprintln!("Fetching: {url}");
-    client::download_file(&url, &zip_path).await?;
+        let filename = url.rsplit('/').next().unwrap_or("gkg.csv.zip");
+        let zip_path = data_dir.path().join(filename);
 
-    let csv_path = decompress::extract_gkg_from_zip(&zip_path, data_dir.path())?;
-    eprintln!("Parsing: {}", csv_path.display());
+        let mut cache = open_download_cache(&args.cache)?;
+        fetch_gkg_archive(
+            &url,
+            entry.as_ref(),
+            &zip_path,
+            client_config,
+            cache.as_mut(),
+            &mut http_cache,
+            cache_ttl,
+            args.cache.no_cache,
+        )
+        .await?;
 
-    let filters = build_filters(&args.filter);
+        let manifest = decompress::extract_gkg_from_zip_verified(&zip_path, data_dir.path(), None)?;
+        let csv_path = data_dir.path().join(&manifest.file_name);
+        eprintln!("Parsing: {}", csv_path.display());
+        vec![csv_path]
+    };
+
+    let filters = build_filters(&args.filter)?;
     let writer = make_writer(&args.output.output)?;
     let format_str = format_to_str(&args.output.format);
-    let mut formatter = output::create_formatter(&format_str, writer, &args.output.fields);
+    let mut formatter = output::create_formatter(
+        &format_str,
+        writer,
+        &args.output.fields,
+        args.output.facet_limit,
+        args.output.output.as_deref(),
+    );
 
-    let reader = open_gkg_file(&csv_path)?;
     run_pipeline(
-        reader,
+        &csv_paths,
         &filters,
         &mut *formatter,
         args.output.offset,
         args.output.limit,
+        args.backfill_translation,
     )
 }
 
@@ -128,65 +284,194 @@ fn cmd_schema(args: SchemaArgs) -> Result<(), NewsfreshError> {
     Ok(())
 }
 
-async fn cmd_analyze(args: AnalyzeArgs) -> Result<(), NewsfreshError> {
-    // Phase 0: Resolve data source
-    let reader: Box<dyn BufRead> = if let Some(ref file) = args.file {
-        open_gkg_file(file)?
+async fn cmd_analyze(args: AnalyzeArgs, client_config: &ClientConfig) -> Result<(), NewsfreshError> {
+    // Phase 0+1: Resolve the data source and parse its records. The
+    // downloaded-file branch parses before `data_dir` drops so at most one
+    // decompressed buffer (the extracted CSV on disk, read lazily) is ever
+    // live alongside the record vector.
+    let (records, errors) = if let Some(ref file) = args.file {
+        let reader = open_gkg_file(file)?;
+        parse_all_records(reader)?
+    } else if let (Some(from), Some(to)) = (&args.range.from, &args.range.to) {
+        let data_dir = resolve_data_dir(args.persist_data_file)?;
+        let timestamps = range::enumerate_slices(from, to)?;
+        eprintln!("Fetching {} slice(s) from {from} to {to}", timestamps.len());
+        let csv_paths = fetch_gkg_range(
+            &timestamps,
+            data_dir.path(),
+            args.range.max_concurrency,
+            client_config,
+            true,
+        )
+        .await?;
+        eprintln!("Parsing {} of {} slice(s)", csv_paths.len(), timestamps.len());
+        parse_all_records_from_paths(&csv_paths)?
     } else {
         let data_dir = resolve_data_dir(args.persist_data_file)?;
-        let url = if let Some(ref date) = args.date {
-            client::historical_url(date)
+        let cache_ttl = args.cache.cache_ttl.map(|m| std::time::Duration::from_secs(m * 60));
+        let mut http_cache = open_http_cache(&args.cache)?;
+        let entry = if args.date.is_some() {
+            None
         } else {
-            let text = client::fetch_text(client::lastupdate_url(args.translation)).await?;
+            let text = http_cache
+                .fetch_text(
+                    client::lastupdate_url(args.translation),
+                    client_config,
+                    cache_ttl,
+                    args.cache.no_cache,
+                )
+                .await?;
             let entries = lastupdate::parse_lastupdate(&text);
-            lastupdate::find_gkg_url(&entries)?
+            Some(lastupdate::find_gkg_entry(&entries)?)
+        };
+        let url = match (&args.date, &entry) {
+            (Some(date), _) => client::historical_url(date),
+            (None, Some(entry)) => entry.url.clone(),
+            (None, None) => unreachable!("entry is always Some when date is None"),
         };
         let filename = url.rsplit('/').next().unwrap_or("gkg.csv.zip");
         let zip_path = data_dir.path().join(filename);
-        eprintln!("Fetching: {url}");
-        client::download_file(&url, &zip_path).await?;
-        let csv_path = decompress::extract_gkg_from_zip(&zip_path, data_dir.path())?;
+        let mut cache = open_download_cache(&args.cache)?;
+        fetch_gkg_archive(
+            &url,
+            entry.as_ref(),
+            &zip_path,
+            client_config,
+            cache.as_mut(),
+            &mut http_cache,
+            cache_ttl,
+            args.cache.no_cache,
+        )
+        .await?;
+        let manifest = decompress::extract_gkg_from_zip_verified(&zip_path, data_dir.path(), None)?;
+        let csv_path = data_dir.path().join(&manifest.file_name);
         eprintln!("Parsing: {}", csv_path.display());
-        // Read entire file into memory since data_dir (if temp) will be dropped
-        let content = std::fs::read_to_string(&csv_path)?;
-        Box::new(BufReader::new(std::io::Cursor::new(content)))
+        let reader = open_gkg_file(&csv_path)?;
+        parse_all_records(reader)?
     };
-
-    // Phase 1: Parse all records
-    let gkg_reader = parse::GkgReader::new(reader);
-    let mut records = Vec::new();
-    let mut errors: usize = 0;
-
-    for result in gkg_reader {
-        let (line_num, line) = result?;
-        match parse::parse_record(&line, line_num) {
-            Ok(record) => records.push(record),
-            Err(e) => {
-                warn!("Skipping line {line_num}: {e}");
-                errors += 1;
-            }
+    let mut records = records;
+    if let Some(threshold) = args.backfill_translation {
+        for record in &mut records {
+            backfill_translation_info(record, threshold);
         }
     }
     eprintln!("Parsed {} records ({errors} errors skipped)", records.len());
 
     // Phase 2: Build index and search (over-fetch 3x for filter headroom)
-    let mut engine = search::create_engine();
+    let mut engine = match args.ranking {
+        RankingMode::Default | RankingMode::Geo => search::create_engine("tantivy"),
+        RankingMode::Bm25 => {
+            let field_weights = match &args.bm25_field_weights {
+                Some(spec) => parse_bm25_field_weights(spec)?,
+                None => search::bm25::FieldWeights::default(),
+            };
+            search::create_bm25_engine_with_params(
+                args.bm25_k1,
+                args.bm25_b,
+                args.typo_tolerance.is_on(),
+                field_weights,
+            )
+        }
+    };
     engine.build(&records)?;
     let fetch_limit = args.limit * 3;
-    let hits = engine.search(&args.search, fetch_limit)?;
+    let hits = match args.ranking {
+        RankingMode::Geo => {
+            if let (Some(lat), Some(lon), Some(radius_km)) =
+                (args.filter.geo_lat, args.filter.geo_lon, args.filter.geo_radius_km)
+            {
+                engine.search_geo(lat, lon, radius_km, fetch_limit)?
+            } else if let Some(ref bbox) = args.filter.geo_bbox {
+                let (top_lat, bottom_lat, left_lon, right_lon) = parse_geo_bbox(bbox)?;
+                engine.search_geo_bbox(bottom_lat, top_lat, left_lon, right_lon, fetch_limit)?
+            } else {
+                return Err(NewsfreshError::FilterExpression(
+                    "--ranking geo requires --geo-lat/--geo-lon/--geo-radius-km or --geo-bbox".to_string(),
+                ));
+            }
+        }
+        _ => engine.search(&args.search, fetch_limit)?,
+    };
     eprintln!("Search returned {} candidates", hits.len());
 
+    // Phase 1.5: Optional hybrid semantic+keyword scoring. Re-ranks the
+    // candidate hits by `ratio * semantic + (1 - ratio) * keyword`, falling
+    // back to the (min-max normalized) keyword score alone for any record
+    // whose embedding failed or wasn't computed.
+    let mut ranking: Vec<usize> = (0..hits.len()).collect();
+    let mut score_details: Vec<Vec<ScoreDetail>> = hits
+        .iter()
+        .map(|hit| vec![ScoreDetail::score("keyword", hit.score)])
+        .collect();
+    let final_scores: Vec<f32> = if args.semantic_ratio > 0.0 {
+        let embedder: Box<dyn embed::Embedder> = match &args.embed_endpoint {
+            Some(endpoint) => Box::new(embed::HttpEmbedder::new(endpoint.clone(), client_config.clone())?),
+            None => Box::new(embed::StubEmbedder::default()),
+        };
+        let mut store = embed::EmbeddingStore::new();
+        let failures = store.build(&records, embedder.as_ref());
+        if failures > 0 {
+            eprintln!(
+                "Warning: {failures} record(s) failed to embed; falling back to keyword score for those"
+            );
+        }
+        let query_embedding = embedder.embed(&args.search).ok();
+
+        let keyword_scores: Vec<f32> = hits.iter().map(|h| h.score).collect();
+        let normalized_keyword = embed::normalize_minmax(&keyword_scores);
+        let scores: Vec<f32> = hits
+            .iter()
+            .zip(normalized_keyword)
+            .zip(score_details.iter_mut())
+            .map(|((hit, keyword), details)| {
+                let record = &records[hit.record_index];
+                let semantic = query_embedding.as_deref().and_then(|query_vector| {
+                    store
+                        .get(&record.gkg_record_id)
+                        .map(|record_vector| embed::cosine_similarity(query_vector, record_vector))
+                });
+                if let Some(similarity) = semantic {
+                    details.push(ScoreDetail::similarity("semantic", similarity));
+                }
+                embed::combine_scores(keyword, semantic, args.semantic_ratio)
+            })
+            .collect();
+        ranking.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap_or(std::cmp::Ordering::Equal));
+        scores
+    } else {
+        hits.iter().map(|h| h.score).collect()
+    };
+
     // Phase 3: Apply structured filters and collect top-N
-    let filters = build_filters(&args.filter);
+    let filters = build_filters(&args.filter)?;
 
-    if args.stats {
+    if let Some(ref facets) = args.facets {
+        let mut aggregator = aggregate::FacetAggregator::new(facets);
+        let mut matched: usize = 0;
+        for &i in &ranking {
+            let hit = &hits[i];
+            let record = &records[hit.record_index];
+            if !filters.matches(record) {
+                continue;
+            }
+            aggregator.add_record(record);
+            matched += 1;
+            if matched >= args.limit {
+                break;
+            }
+        }
+        let distribution = aggregator.finish(args.facet_limit);
+        println!("{}", serde_json::to_string_pretty(&distribution)?);
+        eprintln!("Facets computed over {matched} records ({errors} parse errors)");
+    } else if args.stats {
         let mut filtered = Vec::new();
-        for hit in &hits {
+        for &i in &ranking {
+            let hit = &hits[i];
             let record = &records[hit.record_index];
             if !filters.matches(record) {
                 continue;
             }
-            filtered.push((hit.score, record));
+            filtered.push((final_scores[i], record));
             if filtered.len() >= args.limit {
                 break;
             }
@@ -201,18 +486,32 @@ async fn cmd_analyze(args: AnalyzeArgs) -> Result<(), NewsfreshError> {
     } else {
         let writer = make_writer(&args.output.output)?;
         let format_str = format_to_str(&args.output.format);
-        let mut formatter = output::create_formatter(&format_str, writer, &args.output.fields);
+        let mut formatter = output::create_formatter(
+            &format_str,
+            writer,
+            &args.output.fields,
+            args.facet_limit,
+            args.output.output.as_deref(),
+        );
 
         formatter.begin()?;
 
         let mut count: usize = 0;
-        for hit in &hits {
+        for &i in &ranking {
+            let hit = &hits[i];
             let record = &records[hit.record_index];
             if !filters.matches(record) {
                 continue;
             }
+            let snippets = search::snippet::generate_snippets(
+                &args.search,
+                record,
+                &search::snippet::SnippetConfig::default(),
+            );
             let scored = ScoredRecord {
-                relevance_score: hit.score,
+                relevance_score: final_scores[i],
+                snippet: (!snippets.is_empty()).then(|| snippets.join(" … ")),
+                score_details: score_details[i].clone(),
                 record: record.clone(),
             };
             formatter.write_scored_record(&scored)?;
@@ -231,6 +530,39 @@ async fn cmd_analyze(args: AnalyzeArgs) -> Result<(), NewsfreshError> {
     Ok(())
 }
 
+async fn cmd_watch(args: WatchArgs, client_config: ClientConfig) -> Result<(), NewsfreshError> {
+    use newsfresh::fetch::watch::{self, WatchConfig};
+
+    let config = WatchConfig {
+        interval: std::time::Duration::from_secs(args.interval_secs),
+        translation: args.translation,
+        state_file: args.state_file,
+        data_dir: args.data_dir,
+        max_retries: args.max_retries,
+        client: client_config,
+        http_cache_path: cache_dir(&args.cache).join("http-cache.json"),
+        cache_ttl: args.cache.cache_ttl.map(|m| std::time::Duration::from_secs(m * 60)),
+        bypass_cache: args.cache.no_cache,
+    };
+
+    let writer = make_writer(&args.output.output)?;
+    let format_str = format_to_str(&args.output.format);
+    let mut formatter = output::create_formatter(
+        &format_str,
+        writer,
+        &args.output.fields,
+        args.output.facet_limit,
+        args.output.output.as_deref(),
+    );
+    formatter.begin()?;
+
+    eprintln!(
+        "Watching for new GKG updates every {}s (Ctrl+C to stop)",
+        config.interval.as_secs()
+    );
+    watch::run(config, &mut *formatter).await
+}
+
 /// Holds the download directory — either a temp dir (auto-cleaned) or a persisted path.
 enum DataDir {
     Temp(tempfile::TempDir),
@@ -248,6 +580,130 @@ impl DataDir {
 
 const PERSISTED_STORAGE_DIR: &str = "persisted-storage";
 
+/// The directory backing both persisted caches (download cache + HTTP
+/// cache), overridable with `--cache-dir`.
+fn cache_dir(args: &CacheArgs) -> PathBuf {
+    args.cache_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(PERSISTED_STORAGE_DIR))
+}
+
+/// Opens the persisted download cache unless `args.no_cache` was passed.
+fn open_download_cache(args: &CacheArgs) -> Result<Option<DownloadCache>, NewsfreshError> {
+    if args.no_cache {
+        return Ok(None);
+    }
+    Ok(Some(DownloadCache::open(&cache_dir(args))?))
+}
+
+/// Opens the persisted HTTP cache (ETag/Last-Modified manifest). Unlike
+/// [`open_download_cache`] this is always opened — `args.no_cache` is passed
+/// through to each call instead, so a bypassed request still updates the
+/// manifest for the next one.
+fn open_http_cache(args: &CacheArgs) -> Result<HttpCache, NewsfreshError> {
+    HttpCache::open(cache_dir(args).join("http-cache.json"))
+}
+
+/// Downloads (or reuses a cached copy of) the GKG archive at `url` into
+/// `zip_path`, verifying it against `entry`'s MD5/size when one is known
+/// (i.e. whenever the fetch wasn't pinned to a historical `--date`).
+///
+/// The MD5-keyed `cache` (present only when `entry` is known) is checked
+/// first since it's the stronger guarantee; otherwise the request goes
+/// through `http_cache`, which makes it a conditional GET so a historical
+/// `--date` re-fetched within `cache_ttl` reports a cache hit instead of
+/// re-downloading.
+async fn fetch_gkg_archive(
+    url: &str,
+    entry: Option<&LastUpdateEntry>,
+    zip_path: &Path,
+    client_config: &ClientConfig,
+    mut cache: Option<&mut DownloadCache>,
+    http_cache: &mut HttpCache,
+    cache_ttl: Option<std::time::Duration>,
+    bypass_cache: bool,
+) -> Result<(), NewsfreshError> {
+    if let (Some(entry), Some(cache)) = (entry, cache.as_deref()) {
+        if let Some(cached_path) = cache.get(entry, cache_ttl) {
+            eprintln!("Using cached download: {}", cached_path.display());
+            std::fs::copy(&cached_path, zip_path)?;
+            return Ok(());
+        }
+    }
+
+    let downloaded = http_cache
+        .fetch_file(url, zip_path, client_config, cache_ttl, bypass_cache)
+        .await?;
+    if downloaded {
+        eprintln!("Fetched: {url}");
+    } else {
+        eprintln!("Using cached download (not modified): {}", zip_path.display());
+    }
+
+    if let Some(entry) = entry {
+        verify::verify_download(zip_path, entry)?;
+        if let Some(cache) = cache.as_mut() {
+            cache.insert(entry, zip_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Downloads every slice in `timestamps` concurrently (bounded by
+/// `max_concurrency`) into `dest_dir` and extracts each archive's CSV.
+/// Historical slices carry no `lastupdate` MD5/size entry, so — matching the
+/// single-date fetch path — downloads here are not verified; unlike that
+/// path, they also bypass both the download cache and the HTTP cache.
+/// Slices that 404 are skipped with a warning, since GDELT's historical
+/// archive occasionally has gaps.
+async fn fetch_gkg_range(
+    timestamps: &[String],
+    dest_dir: &Path,
+    max_concurrency: usize,
+    client_config: &ClientConfig,
+    keep_zip: bool,
+) -> Result<Vec<PathBuf>, NewsfreshError> {
+    let downloads: Vec<Result<Option<PathBuf>, NewsfreshError>> =
+        stream::iter(timestamps.iter().cloned())
+            .map(|ts| {
+                let client_config = client_config.clone();
+                let zip_path = dest_dir.join(format!("{ts}.gkg.csv.zip"));
+                async move {
+                    let url = client::historical_url(&ts);
+                    eprintln!("Fetching: {url}");
+                    match client::download_file_with_config(&url, &zip_path, &client_config).await
+                    {
+                        Ok(_) => Ok(Some(zip_path)),
+                        Err(e) if is_not_found(&e) => {
+                            warn!("Skipping {ts}: no GKG file published ({e})");
+                            Ok(None)
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+            })
+            .buffer_unordered(max_concurrency.max(1))
+            .collect()
+            .await;
+
+    let mut csv_paths = Vec::new();
+    for download in downloads {
+        if let Some(zip_path) = download? {
+            let manifest = decompress::extract_gkg_from_zip_verified(&zip_path, dest_dir, None)?;
+            csv_paths.push(dest_dir.join(&manifest.file_name));
+            if !keep_zip {
+                std::fs::remove_file(&zip_path)?;
+            }
+        }
+    }
+    Ok(csv_paths)
+}
+
+/// Whether `err` is an HTTP 404, i.e. GDELT has no file published for that slice.
+fn is_not_found(err: &NewsfreshError) -> bool {
+    matches!(err, NewsfreshError::Http(e) if e.status() == Some(reqwest::StatusCode::NOT_FOUND))
+}
+
 /// Resolve the download directory. If `persist` is true, try to create/use
 /// `persisted-storage/`. On any failure, silently fall back to a temp dir.
 fn resolve_data_dir(persist: bool) -> Result<DataDir, NewsfreshError> {
@@ -268,17 +724,19 @@ fn resolve_data_dir(persist: bool) -> Result<DataDir, NewsfreshError> {
     Ok(DataDir::Temp(tempfile::tempdir()?))
 }
 
-fn build_filters(args: &FilterArgs) -> CompositeFilter {
+fn build_filters(args: &FilterArgs) -> Result<CompositeFilter, NewsfreshError> {
     let mut composite = CompositeFilter::new();
 
     if let Some(ref person) = args.person {
         composite.add(Box::new(PersonFilter {
             pattern: person.clone(),
+            exact_accents: args.exact_accents,
         }));
     }
     if let Some(ref org) = args.org {
         composite.add(Box::new(OrgFilter {
             pattern: org.clone(),
+            exact_accents: args.exact_accents,
         }));
     }
     if let Some(ref theme) = args.theme {
@@ -289,6 +747,7 @@ fn build_filters(args: &FilterArgs) -> CompositeFilter {
     if let Some(ref location) = args.location {
         composite.add(Box::new(LocationFilter {
             pattern: location.clone(),
+            exact_accents: args.exact_accents,
         }));
     }
     if let Some(ref country) = args.country {
@@ -300,12 +759,14 @@ fn build_filters(args: &FilterArgs) -> CompositeFilter {
         composite.add(Box::new(ToneRangeFilter {
             min: args.tone_min,
             max: args.tone_max,
+            ..Default::default()
         }));
     }
     if args.date_from.is_some() || args.date_to.is_some() {
         composite.add(Box::new(DateRangeFilter {
             from: args.date_from.as_ref().and_then(|d| d.parse().ok()),
             to: args.date_to.as_ref().and_then(|d| d.parse().ok()),
+            ..Default::default()
         }));
     }
     if let Some(ref source) = args.source {
@@ -319,29 +780,133 @@ fn build_filters(args: &FilterArgs) -> CompositeFilter {
     if args.has_quote {
         composite.add(Box::new(HasQuoteFilter));
     }
+    if let Some(ref expr) = args.filter_expr {
+        composite.add(filter::parse_filter(expr)?);
+    }
+    if let Some(ref path) = args.filter_file {
+        let text = std::fs::read_to_string(path)?;
+        let predicate: filter::predicates::Predicate = serde_json::from_str(&text)?;
+        composite.add(predicate.compile());
+    }
+    if let (Some(from), Some(to)) = (args.mentioned_date_from, args.mentioned_date_to) {
+        composite.add(Box::new(MentionedDateInRangeFilter { from, to }));
+    }
+    if let (Some(lat), Some(lon), Some(radius_km)) = (args.geo_lat, args.geo_lon, args.geo_radius_km) {
+        composite.add(Box::new(GeoRadiusFilter { lat, lon, radius_km }));
+    }
+    if let Some(ref bbox) = args.geo_bbox {
+        let (top_lat, bottom_lat, left_lon, right_lon) = parse_geo_bbox(bbox)?;
+        composite.add(Box::new(GeoBoundingBoxFilter::new(top_lat, bottom_lat, left_lon, right_lon)?));
+    }
 
-    composite
+    Ok(composite)
+}
+
+/// Parses a `--bm25-field-weights` value of the form
+/// `"field=weight,field=weight,..."` into a [`search::bm25::FieldWeights`],
+/// leaving unlisted fields at their default `1.0`.
+fn parse_bm25_field_weights(spec: &str) -> Result<search::bm25::FieldWeights, NewsfreshError> {
+    let mut weights = search::bm25::FieldWeights::default();
+    for pair in spec.split(',') {
+        let pair = pair.trim();
+        if pair.is_empty() {
+            continue;
+        }
+        let (field, weight) = pair.trim().split_once('=').ok_or_else(|| {
+            NewsfreshError::FilterExpression(format!(
+                "--bm25-field-weights: expected \"field=weight\", got \"{pair}\""
+            ))
+        })?;
+        let weight: f32 = weight.trim().parse().map_err(|_| {
+            NewsfreshError::FilterExpression(format!(
+                "--bm25-field-weights: invalid number \"{weight}\""
+            ))
+        })?;
+        match field.trim() {
+            "source" => weights.source = weight,
+            "persons" => weights.persons = weight,
+            "organizations" => weights.organizations = weight,
+            "themes" => weights.themes = weight,
+            "locations" => weights.locations = weight,
+            "quotations" => weights.quotations = weight,
+            "names" => weights.names = weight,
+            other => {
+                return Err(NewsfreshError::FilterExpression(format!(
+                    "--bm25-field-weights: unknown field \"{other}\""
+                )))
+            }
+        }
+    }
+    Ok(weights)
+}
+
+/// Parses a `--geo-bbox` value of the form `"top_lat,bottom_lat,left_lon,right_lon"`.
+fn parse_geo_bbox(bbox: &str) -> Result<(f64, f64, f64, f64), NewsfreshError> {
+    let parts: Vec<&str> = bbox.split(',').map(str::trim).collect();
+    let [top_lat, bottom_lat, left_lon, right_lon] = parts.as_slice() else {
+        return Err(NewsfreshError::FilterExpression(format!(
+            "--geo-bbox expects \"top_lat,bottom_lat,left_lon,right_lon\", got \"{bbox}\""
+        )));
+    };
+    let parse = |s: &str| {
+        s.parse::<f64>()
+            .map_err(|_| NewsfreshError::FilterExpression(format!("--geo-bbox: invalid number \"{s}\"")))
+    };
+    Ok((parse(top_lat)?, parse(bottom_lat)?, parse(left_lon)?, parse(right_lon)?))
 }
 
 fn open_gkg_file(path: &Path) -> Result<Box<dyn BufRead>, NewsfreshError> {
     if path.extension().and_then(|e| e.to_str()) == Some("zip") {
-        let content = decompress::read_gkg_from_zip(path)?;
-        Ok(Box::new(BufReader::new(std::io::Cursor::new(content))))
+        decompress::stream_gkg_from_zip(path)
     } else {
         let file = std::fs::File::open(path)?;
         Ok(Box::new(BufReader::new(file)))
     }
 }
 
+/// Parses every record out of `reader`, warning on and counting per-line
+/// errors rather than failing the whole pipeline.
+fn parse_all_records(reader: Box<dyn BufRead>) -> Result<(Vec<GkgRecord>, usize), NewsfreshError> {
+    let gkg_reader = parse::GkgReader::new(reader);
+    let mut records = Vec::new();
+    let mut errors: usize = 0;
+
+    for result in gkg_reader {
+        let (line_num, line) = result?;
+        match parse::parse_record(&line, line_num) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                warn!("Skipping line {line_num}: {e}");
+                errors += 1;
+            }
+        }
+    }
+
+    Ok((records, errors))
+}
+
+/// Parses every record across `paths` in order, concatenating them into one
+/// record set so a multi-slice date range is filtered/searched as a whole.
+fn parse_all_records_from_paths(paths: &[PathBuf]) -> Result<(Vec<GkgRecord>, usize), NewsfreshError> {
+    let mut records = Vec::new();
+    let mut errors: usize = 0;
+    for path in paths {
+        let reader = open_gkg_file(path)?;
+        let (mut file_records, file_errors) = parse_all_records(reader)?;
+        records.append(&mut file_records);
+        errors += file_errors;
+    }
+    Ok((records, errors))
+}
+
 fn run_pipeline(
-    reader: Box<dyn BufRead>,
+    paths: &[PathBuf],
     filters: &CompositeFilter,
     formatter: &mut dyn OutputFormatter,
     offset: Option<usize>,
     limit: Option<usize>,
+    backfill_translation: Option<f32>,
 ) -> Result<(), NewsfreshError> {
-    let gkg_reader = parse::GkgReader::new(reader);
-
     formatter.begin()?;
 
     let mut count: usize = 0;
@@ -349,28 +914,36 @@ fn run_pipeline(
     let mut errors: usize = 0;
     let skip_n = offset.unwrap_or(0);
 
-    for result in gkg_reader {
-        let (line_num, line) = result?;
-        match parse::parse_record(&line, line_num) {
-            Ok(record) => {
-                if !filters.matches(&record) {
-                    continue;
+    'paths: for path in paths {
+        let reader = open_gkg_file(path)?;
+        let gkg_reader = parse::GkgReader::new(reader);
+
+        for result in gkg_reader {
+            let (line_num, line) = result?;
+            match parse::parse_record(&line, line_num) {
+                Ok(mut record) => {
+                    if let Some(threshold) = backfill_translation {
+                        backfill_translation_info(&mut record, threshold);
+                    }
+                    if !filters.matches(&record) {
+                        continue;
+                    }
+                    if skipped < skip_n {
+                        skipped += 1;
+                        continue;
+                    }
+                    formatter.write_record(&record)?;
+                    count += 1;
+                    if let Some(lim) = limit
+                        && count >= lim
+                    {
+                        break 'paths;
+                    }
                 }
-                if skipped < skip_n {
-                    skipped += 1;
-                    continue;
+                Err(e) => {
+                    warn!("Skipping line {line_num}: {e}");
+                    errors += 1;
                 }
-                formatter.write_record(&record)?;
-                count += 1;
-                if let Some(lim) = limit
-                    && count >= lim
-                {
-                    break;
-                }
-            }
-            Err(e) => {
-                warn!("Skipping line {line_num}: {e}");
-                errors += 1;
             }
         }
     }
@@ -395,7 +968,13 @@ fn format_to_str(format: &OutputFormat) -> String {
     match format {
         OutputFormat::Json => "json".to_string(),
         OutputFormat::JsonCompact => "json-compact".to_string(),
+        OutputFormat::JsonLines => "json-lines".to_string(),
         OutputFormat::Tealeaf => "tealeaf".to_string(),
         OutputFormat::TealeafCompact => "tealeaf-compact".to_string(),
+        OutputFormat::Msgpack => "msgpack".to_string(),
+        OutputFormat::Parquet => "parquet".to_string(),
+        OutputFormat::Facets => "facets".to_string(),
+        OutputFormat::Html => "html".to_string(),
+        OutputFormat::Sqlite => "sqlite".to_string(),
     }
 }
@@ -8,6 +8,7 @@ use super::location::{EnhancedLocation, LocationV1};
 use super::name::NameEntry;
 use super::person::EnhancedEntity;
 use super::quotation::Quotation;
+use super::score_detail::ScoreDetail;
 use super::theme::EnhancedTheme;
 use super::tone::Tone;
 use super::translation::TranslationInfo;
@@ -15,6 +16,15 @@ use super::translation::TranslationInfo;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoredRecord {
     pub relevance_score: f32,
+    /// Highlighted excerpt(s) drawn from the record's text, when a search
+    /// query produced this score. `None` when no snippet was requested.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub snippet: Option<String>,
+    /// Ordered, per-ranking-stage contributions that produced
+    /// `relevance_score` (e.g. keyword, semantic, tone) — a rule trace for
+    /// debugging why a record ranked where it did.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub score_details: Vec<ScoreDetail>,
     #[serde(flatten)]
     pub record: GkgRecord,
 }
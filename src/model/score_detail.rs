@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// One named contribution to a `ScoredRecord`'s `relevance_score`, e.g.
+/// `{ "rule": "keyword", "score": 0.72 }` or
+/// `{ "rule": "semantic", "similarity": 0.81 }`. `ScoredRecord::score_details`
+/// holds these in the order each ranking stage applied them, so downstream
+/// tooling can reproduce and debug the ranking like a rule trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreDetail {
+    pub rule: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub score: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub similarity: Option<f32>,
+}
+
+impl ScoreDetail {
+    pub fn score(rule: impl Into<String>, score: f32) -> Self {
+        Self {
+            rule: rule.into(),
+            score: Some(score),
+            similarity: None,
+        }
+    }
+
+    pub fn similarity(rule: impl Into<String>, similarity: f32) -> Self {
+        Self {
+            rule: rule.into(),
+            score: None,
+            similarity: Some(similarity),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use super::{stopwords, Language};
+
+const CANDIDATES: &[Language] = &[
+    Language::English,
+    Language::Spanish,
+    Language::French,
+    Language::German,
+    Language::Portuguese,
+];
+
+/// Characters whose presence is a strong (if not exclusive) hint for a
+/// language, used to break ties when stopword overlap alone is ambiguous.
+fn script_bonus(text: &str, lang: Language) -> u32 {
+    let marker_chars: &[char] = match lang {
+        Language::Spanish => &['ñ', '¿', '¡'],
+        Language::French => &['ç', 'è', 'œ'],
+        Language::German => &['ß', 'ü', 'ö', 'ä'],
+        Language::Portuguese => &['ã', 'õ', 'ç'],
+        Language::English => &[],
+    };
+    text.chars().filter(|c| marker_chars.contains(c)).count() as u32
+}
+
+/// Detects the most likely language of `text` from token/script statistics,
+/// used as a fallback when a GKG record's `translation_info` is absent.
+///
+/// Scores each candidate language by how many of its tokens appear in that
+/// language's stopword list, plus a bonus for language-specific diacritics,
+/// and returns the highest-scoring candidate. Defaults to English when no
+/// language scores above zero (e.g. for very short or ambiguous text).
+pub fn detect(text: &str) -> Language {
+    detect_with_confidence(text).0
+}
+
+/// Like [`detect`], but also returns a confidence score in `[0.0, 1.0]`:
+/// the winning language's score (stopword hits plus diacritic bonus) as a
+/// share of the maximum score every token could have contributed. `0.0`
+/// means nothing matched (or there was no text at all), distinguishing a
+/// confident call from the English default used when scores tie at zero.
+pub fn detect_with_confidence(text: &str) -> (Language, f32) {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let mut best = Language::English;
+    let mut best_score: u32 = 0;
+
+    for &lang in CANDIDATES {
+        let table = stopwords(lang);
+        let hits = words.iter().filter(|w| table.contains(*w)).count() as u32;
+        let score = hits * 2 + script_bonus(&lower, lang);
+        if score > best_score {
+            best_score = score;
+            best = lang;
+        }
+    }
+
+    if words.is_empty() {
+        return (best, 0.0);
+    }
+    let confidence = (best_score as f32 / (words.len() as f32 * 2.0)).min(1.0);
+    (best, confidence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_spanish() {
+        let text = "el presidente dijo que la economia es fuerte";
+        assert_eq!(detect(text), Language::Spanish);
+    }
+
+    #[test]
+    fn detects_french() {
+        let text = "le president a dit que la situation est grave";
+        assert_eq!(detect(text), Language::French);
+    }
+
+    #[test]
+    fn defaults_to_english_for_empty_text() {
+        assert_eq!(detect(""), Language::English);
+    }
+
+    #[test]
+    fn detects_english() {
+        let text = "the president said that the economy is strong";
+        assert_eq!(detect(text), Language::English);
+    }
+
+    #[test]
+    fn detect_with_confidence_returns_zero_for_empty_text() {
+        assert_eq!(detect_with_confidence(""), (Language::English, 0.0));
+    }
+
+    #[test]
+    fn detect_with_confidence_scores_a_strong_match_higher_than_a_weak_one() {
+        let strong = "el presidente dijo que la economia es fuerte para la nacion";
+        let weak = "el dato es nuevo";
+        let (strong_lang, strong_confidence) = detect_with_confidence(strong);
+        let (weak_lang, weak_confidence) = detect_with_confidence(weak);
+        assert_eq!(strong_lang, Language::Spanish);
+        assert_eq!(weak_lang, Language::Spanish);
+        assert!(strong_confidence > weak_confidence);
+    }
+}
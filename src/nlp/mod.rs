@@ -0,0 +1,93 @@
+//! Lightweight, dependency-free NLP helpers: language identification,
+//! per-language stopword tables, and rule-based stemming.
+//!
+//! These back the search tokenizer so that non-English GKG records (flagged
+//! via `translation_info`, or auto-detected when that field is absent) are
+//! indexed with stopwords and suffixes appropriate to their language instead
+//! of being treated as raw English text.
+
+mod detect;
+mod source_language;
+mod stem;
+mod stopwords;
+
+pub use detect::{detect, detect_with_confidence};
+pub use source_language::{backfill_translation_info, detect_language};
+pub use stem::stem;
+pub use stopwords::stopwords;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    English,
+    Spanish,
+    French,
+    German,
+    Portuguese,
+}
+
+impl Language {
+    /// Maps a GDELT `translation_info.source_language` code (ISO 639-1-ish,
+    /// as GDELT emits it) to a supported [`Language`]. Unrecognized or
+    /// missing codes fall back to English.
+    pub fn from_code(code: &str) -> Self {
+        match code.to_lowercase().as_str() {
+            "es" | "spa" => Self::Spanish,
+            "fr" | "fra" | "fre" => Self::French,
+            "de" | "deu" | "ger" => Self::German,
+            "pt" | "por" => Self::Portuguese,
+            _ => Self::English,
+        }
+    }
+
+    /// The ISO 639-3 code for this language, the inverse of [`Self::from_code`]'s
+    /// normalization — used when writing a detected language back out (e.g.
+    /// to `TranslationInfo.source_language`) rather than reading one in.
+    pub fn to_iso639_3(self) -> &'static str {
+        match self {
+            Self::English => "eng",
+            Self::Spanish => "spa",
+            Self::French => "fra",
+            Self::German => "deu",
+            Self::Portuguese => "por",
+        }
+    }
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self::English
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_code_recognizes_known_languages() {
+        assert_eq!(Language::from_code("es"), Language::Spanish);
+        assert_eq!(Language::from_code("FR"), Language::French);
+        assert_eq!(Language::from_code("deu"), Language::German);
+    }
+
+    #[test]
+    fn from_code_defaults_to_english() {
+        assert_eq!(Language::from_code("zz"), Language::English);
+        assert_eq!(Language::from_code(""), Language::English);
+    }
+
+    #[test]
+    fn to_iso639_3_round_trips_through_from_code() {
+        for lang in [
+            Language::English,
+            Language::Spanish,
+            Language::French,
+            Language::German,
+            Language::Portuguese,
+        ] {
+            assert_eq!(Language::from_code(lang.to_iso639_3()), lang);
+        }
+    }
+}
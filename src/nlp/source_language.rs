@@ -0,0 +1,152 @@
+use crate::model::{GkgRecord, TranslationInfo};
+
+use super::detect::detect_with_confidence;
+
+/// Gathers the record's textual fields most likely to carry the source
+/// article's actual prose, in the same spirit as `search::enrich`'s
+/// per-field text collection: quotations, extracted names, and the source
+/// outlet's name.
+fn collect_text(record: &GkgRecord) -> String {
+    let mut parts: Vec<&str> = record.quotations.iter().map(|q| q.quote.as_str()).collect();
+    parts.extend(record.all_names.iter().map(|n| n.name.as_str()));
+    parts.push(&record.source_common_name);
+    parts.join(" ")
+}
+
+/// Infers a source language for `record` from its textual fields via
+/// [`detect_with_confidence`], returning an ISO 639-3 code and a confidence
+/// score in `[0.0, 1.0]`. Returns `None` when the record has no text to
+/// classify, regardless of whether GDELT already supplied a
+/// `translation_info` — callers decide whether to prefer the detected value.
+pub fn detect_language(record: &GkgRecord) -> Option<(String, f32)> {
+    let text = collect_text(record);
+    if text.trim().is_empty() {
+        return None;
+    }
+    let (language, confidence) = detect_with_confidence(&text);
+    Some((language.to_iso639_3().to_string(), confidence))
+}
+
+/// Fills `record.translation_info` from [`detect_language`] when GDELT
+/// didn't already supply one and the detector's confidence clears
+/// `threshold`. The backfilled value leaves `engine` empty, distinguishing
+/// it from a GDELT-provided `TranslationInfo` (which always carries an
+/// engine tag), so language-based filter predicates work uniformly across
+/// translated and untranslated records. Returns whether a value was filled
+/// in.
+pub fn backfill_translation_info(record: &mut GkgRecord, threshold: f32) -> bool {
+    if record.translation_info.is_some() {
+        return false;
+    }
+
+    match detect_language(record) {
+        Some((language, confidence)) if confidence >= threshold => {
+            record.translation_info = Some(TranslationInfo { source_language: language, engine: String::new() });
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Quotation;
+
+    fn make_test_record() -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "example.com".into(),
+            document_identifier: "https://example.com/a".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec![],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec![],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn detect_language_returns_none_for_empty_text() {
+        let mut record = make_test_record();
+        record.source_common_name = String::new();
+        assert!(detect_language(&record).is_none());
+    }
+
+    #[test]
+    fn detect_language_classifies_spanish_quotations() {
+        let mut record = make_test_record();
+        record.quotations = vec![Quotation {
+            offset: 0,
+            length: 0,
+            verb: "dijo".into(),
+            quote: "el presidente dijo que la economia es fuerte para la nacion".into(),
+        }];
+        let (language, confidence) = detect_language(&record).unwrap();
+        assert_eq!(language, "spa");
+        assert!(confidence > 0.0);
+    }
+
+    #[test]
+    fn backfill_translation_info_fills_when_confidence_clears_threshold() {
+        let mut record = make_test_record();
+        record.quotations = vec![Quotation {
+            offset: 0,
+            length: 0,
+            verb: "dijo".into(),
+            quote: "el presidente dijo que la economia es fuerte para la nacion".into(),
+        }];
+        assert!(backfill_translation_info(&mut record, 0.3));
+        let info = record.translation_info.unwrap();
+        assert_eq!(info.source_language, "spa");
+        assert_eq!(info.engine, "");
+    }
+
+    #[test]
+    fn backfill_translation_info_skips_when_below_threshold() {
+        let mut record = make_test_record();
+        record.quotations = vec![Quotation {
+            offset: 0,
+            length: 0,
+            verb: "said".into(),
+            quote: "a short note".into(),
+        }];
+        assert!(!backfill_translation_info(&mut record, 0.95));
+        assert!(record.translation_info.is_none());
+    }
+
+    #[test]
+    fn backfill_translation_info_does_not_overwrite_existing_value() {
+        let mut record = make_test_record();
+        record.translation_info = Some(TranslationInfo { source_language: "ara".into(), engine: "1".into() });
+        record.quotations = vec![Quotation {
+            offset: 0,
+            length: 0,
+            verb: "dijo".into(),
+            quote: "el presidente dijo que la economia es fuerte para la nacion".into(),
+        }];
+        assert!(!backfill_translation_info(&mut record, 0.1));
+        let info = record.translation_info.unwrap();
+        assert_eq!(info.source_language, "ara");
+        assert_eq!(info.engine, "1");
+    }
+}
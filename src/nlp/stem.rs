@@ -0,0 +1,42 @@
+use super::Language;
+
+/// Rule-based suffix stripping per language — no Porter/Snowball algorithm,
+/// just enough folding to merge common plural/verb forms during indexing.
+pub fn stem(token: &str, lang: Language) -> String {
+    let suffixes: &[&str] = match lang {
+        Language::English => &["ing", "edly", "ed", "ies", "es", "s"],
+        Language::Spanish => &["mente", "ando", "iendo", "ados", "adas", "os", "as", "o", "a"],
+        Language::French => &["ement", "aient", "ons", "ez", "es", "e", "s"],
+        Language::German => &["ungen", "ung", "lich", "en", "er", "e"],
+        Language::Portuguese => &["mente", "ando", "endo", "ados", "adas", "os", "as", "o", "a"],
+    };
+
+    for suffix in suffixes {
+        if let Some(stripped) = token.strip_suffix(suffix)
+            && stripped.len() >= 3
+        {
+            return stripped.to_string();
+        }
+    }
+    token.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_folds_plurals() {
+        assert_eq!(stem("tariffs", Language::English), stem("tariff", Language::English));
+    }
+
+    #[test]
+    fn spanish_folds_adverbs() {
+        assert_eq!(stem("rapidamente", Language::Spanish), "rapida");
+    }
+
+    #[test]
+    fn short_tokens_are_left_alone() {
+        assert_eq!(stem("as", Language::English), "as");
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use super::Language;
+
+const ENGLISH: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "he", "in", "is", "it",
+    "its", "of", "on", "that", "the", "to", "was", "were", "will", "with", "this", "but", "they",
+    "have", "had", "not", "or", "his", "her", "she", "their", "said", "says",
+];
+
+const SPANISH: &[&str] = &[
+    "el", "la", "los", "las", "de", "del", "y", "en", "que", "un", "una", "es", "se", "su", "por",
+    "con", "para", "no", "como", "lo", "al", "dijo", "dijeron",
+];
+
+const FRENCH: &[&str] = &[
+    "le", "la", "les", "de", "du", "des", "et", "en", "que", "un", "une", "est", "se", "son",
+    "pour", "avec", "ne", "pas", "au", "dit",
+];
+
+const GERMAN: &[&str] = &[
+    "der", "die", "das", "und", "in", "zu", "den", "von", "ist", "ein", "eine", "mit", "auf",
+    "sich", "des", "fur", "nicht", "sagte",
+];
+
+const PORTUGUESE: &[&str] = &[
+    "o", "a", "os", "as", "de", "do", "da", "dos", "das", "e", "em", "que", "um", "uma", "e",
+    "para", "com", "nao", "ao", "disse",
+];
+
+struct StopwordTables {
+    english: HashSet<&'static str>,
+    spanish: HashSet<&'static str>,
+    french: HashSet<&'static str>,
+    german: HashSet<&'static str>,
+    portuguese: HashSet<&'static str>,
+}
+
+fn tables() -> &'static StopwordTables {
+    static TABLES: OnceLock<StopwordTables> = OnceLock::new();
+    TABLES.get_or_init(|| StopwordTables {
+        english: ENGLISH.iter().copied().collect(),
+        spanish: SPANISH.iter().copied().collect(),
+        french: FRENCH.iter().copied().collect(),
+        german: GERMAN.iter().copied().collect(),
+        portuguese: PORTUGUESE.iter().copied().collect(),
+    })
+}
+
+/// Returns the stopword set for `lang`.
+pub fn stopwords(lang: Language) -> &'static HashSet<&'static str> {
+    let t = tables();
+    match lang {
+        Language::English => &t.english,
+        Language::Spanish => &t.spanish,
+        Language::French => &t.french,
+        Language::German => &t.german,
+        Language::Portuguese => &t.portuguese,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_language_has_its_own_table() {
+        assert!(stopwords(Language::English).contains("the"));
+        assert!(stopwords(Language::Spanish).contains("el"));
+        assert!(stopwords(Language::French).contains("le"));
+        assert!(stopwords(Language::German).contains("der"));
+        assert!(stopwords(Language::Portuguese).contains("o"));
+    }
+
+    #[test]
+    fn tables_do_not_cross_contaminate() {
+        assert!(!stopwords(Language::Spanish).contains("the"));
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::Write;
+
+use super::OutputFormatter;
+use crate::aggregate::FacetAggregator;
+use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoredRecord};
+
+/// Instead of emitting one document per record, accumulates facet counts
+/// over the whole matched set and writes a single summary JSON object at
+/// [`finish`](OutputFormatter::finish), turning a streaming pass over a GKG
+/// file into a quick aggregation report (top themes, top persons, tone
+/// distribution, ...) without a separate pass over the data.
+pub struct FacetFormatter {
+    writer: Box<dyn Write>,
+    aggregator: FacetAggregator,
+    top_n: usize,
+}
+
+impl FacetFormatter {
+    pub fn new(writer: Box<dyn Write>, facets: Vec<String>, top_n: usize) -> Self {
+        Self { writer, aggregator: FacetAggregator::new(&facets), top_n }
+    }
+}
+
+impl OutputFormatter for FacetFormatter {
+    fn begin(&mut self) -> Result<(), NewsfreshError> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &GkgRecord) -> Result<(), NewsfreshError> {
+        self.aggregator.add_record(record);
+        Ok(())
+    }
+
+    fn write_scored_record(&mut self, scored: &ScoredRecord) -> Result<(), NewsfreshError> {
+        self.write_record(&scored.record)
+    }
+
+    fn finish(&mut self) -> Result<(), NewsfreshError> {
+        let distribution = self.aggregator.finish(self.top_n);
+        writeln!(self.writer, "{}", serde_json::to_string_pretty(&distribution)?)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::model::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn make_test_record(source: &str) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: source.into(),
+            document_identifier: "https://example.com/a".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec![],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec![],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn writes_top_n_facet_summary_on_finish() {
+        let shared = SharedBuf::default();
+        let mut fmt =
+            FacetFormatter::new(Box::new(shared.clone()), vec!["source".to_string()], 0);
+        fmt.begin().unwrap();
+        fmt.write_record(&make_test_record("a.com")).unwrap();
+        fmt.write_record(&make_test_record("a.com")).unwrap();
+        fmt.write_record(&make_test_record("b.com")).unwrap();
+        fmt.finish().unwrap();
+
+        let buf = shared.0.lock().unwrap().clone();
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        let source = value["source"].as_array().unwrap();
+        assert_eq!(source[0], serde_json::json!({ "value": "a.com", "count": 2 }));
+        assert_eq!(source[1], serde_json::json!({ "value": "b.com", "count": 1 }));
+    }
+
+    #[test]
+    fn write_record_does_not_emit_per_record_output() {
+        let shared = SharedBuf::default();
+        let mut fmt =
+            FacetFormatter::new(Box::new(shared.clone()), vec!["source".to_string()], 0);
+        fmt.write_record(&make_test_record("a.com")).unwrap();
+        assert!(shared.0.lock().unwrap().is_empty());
+    }
+}
@@ -1,21 +1,115 @@
+use serde_json::Value;
+
 use crate::error::NewsfreshError;
 use crate::model::GkgRecord;
 
+/// Projects `record` down to the dot-separated paths in `fields`, e.g.
+/// `"tone.polarity"` or `"v2_enhanced_locations.full_name"` (the latter maps
+/// over every array element), reconstructing a minimal nested object rather
+/// than a flat `"a.b"`-keyed map. A path prefixed with `-` (e.g. `-gcam`) is
+/// an exclusion instead: "everything except this". Exclusions always win
+/// over inclusions of the same path, and are applied against the full record
+/// when no inclusion paths are given.
 pub fn project_record(
     record: &GkgRecord,
     fields: &[String],
-) -> Result<serde_json::Value, NewsfreshError> {
+) -> Result<Value, NewsfreshError> {
     let full = serde_json::to_value(record)?;
-    let Some(obj) = full.as_object() else {
-        return Ok(full);
-    };
-    let mut projected = serde_json::Map::new();
+
+    let mut includes: Vec<&str> = Vec::new();
+    let mut excludes: Vec<&str> = Vec::new();
     for field in fields {
-        if let Some(val) = obj.get(field) {
-            projected.insert(field.clone(), val.clone());
+        match field.strip_prefix('-') {
+            Some(path) => excludes.push(path),
+            None => includes.push(field.as_str()),
+        }
+    }
+
+    let mut projected = if includes.is_empty() && excludes.is_empty() {
+        Value::Object(serde_json::Map::new())
+    } else if includes.is_empty() {
+        full
+    } else {
+        let mut out = Value::Null;
+        for path in &includes {
+            let segments: Vec<&str> = path.split('.').collect();
+            insert_path(&mut out, &full, &segments);
         }
+        if out.is_null() {
+            out = Value::Object(serde_json::Map::new());
+        }
+        out
+    };
+
+    for path in &excludes {
+        let segments: Vec<&str> = path.split('.').collect();
+        remove_path(&mut projected, &segments);
+    }
+
+    Ok(projected)
+}
+
+/// Copies the value at `segments` from `source` into the equivalent place in
+/// `target`, descending through objects and, for arrays, mapping the
+/// remaining path over every element.
+fn insert_path(target: &mut Value, source: &Value, segments: &[&str]) {
+    if segments.is_empty() {
+        *target = source.clone();
+        return;
+    }
+    match source {
+        Value::Object(src_map) => {
+            let (head, rest) = (segments[0], &segments[1..]);
+            let Some(child_source) = src_map.get(head) else {
+                return;
+            };
+            if !target.is_object() {
+                *target = Value::Object(serde_json::Map::new());
+            }
+            let child_target = target
+                .as_object_mut()
+                .unwrap()
+                .entry(head.to_string())
+                .or_insert(Value::Null);
+            insert_path(child_target, child_source, rest);
+        }
+        Value::Array(src_arr) => {
+            if !target.is_array() {
+                *target = Value::Array(vec![Value::Null; src_arr.len()]);
+            }
+            let target_arr = target.as_array_mut().unwrap();
+            if target_arr.len() < src_arr.len() {
+                target_arr.resize(src_arr.len(), Value::Null);
+            }
+            for (item_target, item_source) in target_arr.iter_mut().zip(src_arr) {
+                insert_path(item_target, item_source, segments);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Removes the value at `segments` from `target`, mapping over array
+/// elements when the path passes through one.
+fn remove_path(target: &mut Value, segments: &[&str]) {
+    let Some((head, rest)) = segments.split_first() else {
+        return;
+    };
+    match target {
+        Value::Object(map) => {
+            if rest.is_empty() {
+                map.remove(*head);
+            } else if let Some(child) = map.get_mut(*head) {
+                remove_path(child, rest);
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr.iter_mut() {
+                remove_path(item, segments);
+            }
+        }
+        _ => {}
     }
-    Ok(serde_json::Value::Object(projected))
 }
 
 #[cfg(test)]
@@ -71,4 +165,43 @@ mod tests {
         let obj = result.as_object().unwrap();
         assert!(obj.is_empty());
     }
+
+    #[test]
+    fn test_project_nested_path_descends_into_object() {
+        let record = make_test_record();
+        let fields = vec!["tone.polarity".to_string()];
+        let result = project_record(&record, &fields).unwrap();
+        assert_eq!(result, serde_json::json!({"tone": {"polarity": 5.5}}));
+    }
+
+    #[test]
+    fn test_project_nested_path_maps_over_array() {
+        let record = make_test_record();
+        let fields = vec!["v1_locations.full_name".to_string()];
+        let result = project_record(&record, &fields).unwrap();
+        assert_eq!(
+            result,
+            serde_json::json!({"v1_locations": [{"full_name": "United States"}]})
+        );
+    }
+
+    #[test]
+    fn test_project_exclusion_removes_top_level_field() {
+        let record = make_test_record();
+        let fields = vec!["-gcam".to_string(), "-extras_xml".to_string()];
+        let result = project_record(&record, &fields).unwrap();
+        let obj = result.as_object().unwrap();
+        assert!(!obj.contains_key("gcam"));
+        assert!(!obj.contains_key("extras_xml"));
+        assert!(obj.contains_key("document_identifier"));
+    }
+
+    #[test]
+    fn test_exclusion_takes_precedence_over_inclusion_of_same_path() {
+        let record = make_test_record();
+        let fields = vec!["tone".to_string(), "-tone".to_string()];
+        let result = project_record(&record, &fields).unwrap();
+        let obj = result.as_object().unwrap();
+        assert!(!obj.contains_key("tone"));
+    }
 }
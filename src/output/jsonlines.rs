@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use super::field_select::project_record;
+use super::OutputFormatter;
+use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoredRecord};
+
+/// Emits each record as a single compact JSON object terminated by `\n` —
+/// newline-delimited JSON (NDJSON) — rather than `JsonFormatter`'s
+/// comma-separated `[ ... ]` array, so a bulk-indexing endpoint can consume
+/// the stream one document at a time without buffering the whole result set.
+pub struct JsonLinesFormatter {
+    writer: Box<dyn Write>,
+    fields: Option<Vec<String>>,
+}
+
+impl JsonLinesFormatter {
+    pub fn new(writer: Box<dyn Write>, fields: Option<Vec<String>>) -> Self {
+        Self { writer, fields }
+    }
+}
+
+impl OutputFormatter for JsonLinesFormatter {
+    fn begin(&mut self) -> Result<(), NewsfreshError> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &GkgRecord) -> Result<(), NewsfreshError> {
+        let json_str = if let Some(ref fields) = self.fields {
+            serde_json::to_string(&project_record(record, fields)?)?
+        } else {
+            serde_json::to_string(record)?
+        };
+        writeln!(self.writer, "{json_str}")?;
+        Ok(())
+    }
+
+    fn write_scored_record(&mut self, scored: &ScoredRecord) -> Result<(), NewsfreshError> {
+        writeln!(self.writer, "{}", serde_json::to_string(scored)?)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), NewsfreshError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::model::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn make_test_record(id: &str) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: id.into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec!["LEADER".into()],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec!["donald trump".into()],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn writes_one_compact_json_object_per_line() {
+        let shared = SharedBuf::default();
+        let mut fmt = JsonLinesFormatter::new(Box::new(shared.clone()), None);
+        fmt.begin().unwrap();
+        fmt.write_record(&make_test_record("1")).unwrap();
+        fmt.write_record(&make_test_record("2")).unwrap();
+        fmt.finish().unwrap();
+
+        let buf = shared.0.lock().unwrap().clone();
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for (line, id) in lines.iter().zip(["1", "2"]) {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["gkg_record_id"], id);
+        }
+    }
+
+    #[test]
+    fn honors_field_projection() {
+        let shared = SharedBuf::default();
+        let mut fmt = JsonLinesFormatter::new(
+            Box::new(shared.clone()),
+            Some(vec!["gkg_record_id".to_string()]),
+        );
+        fmt.begin().unwrap();
+        fmt.write_record(&make_test_record("1")).unwrap();
+        fmt.finish().unwrap();
+
+        let buf = shared.0.lock().unwrap().clone();
+        let line = String::from_utf8(buf).unwrap();
+        assert_eq!(line.matches('\n').count(), 1);
+        let value: serde_json::Value = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(value, serde_json::json!({"gkg_record_id": "1"}));
+    }
+}
@@ -1,9 +1,14 @@
+pub mod facets;
 pub mod field_select;
 pub mod json;
+pub mod jsonlines;
+pub mod msgpack;
+pub mod parquet;
 pub mod schema;
 pub mod tealeaf;
 
 use std::io::Write;
+use std::path::Path;
 
 use crate::error::NewsfreshError;
 use crate::model::{GkgRecord, ScoredRecord};
@@ -21,10 +26,26 @@ pub fn create_formatter(
     format: &str,
     writer: Box<dyn Write>,
     fields: &Option<Vec<String>>,
+    facet_limit: usize,
+    output_path: Option<&Path>,
 ) -> Box<dyn OutputFormatter> {
     match format {
         "tealeaf" => Box::new(tealeaf::TealeafFormatter::new(writer, false)),
         "tealeaf-compact" => Box::new(tealeaf::TealeafFormatter::new(writer, true)),
+        "json-lines" => Box::new(jsonlines::JsonLinesFormatter::new(writer, fields.clone())),
+        "msgpack" => Box::new(msgpack::MsgpackFormatter::new(writer)),
+        "parquet" => Box::new(parquet::ParquetFormatter::new(writer)),
+        "facets" => {
+            Box::new(facets::FacetFormatter::new(writer, fields.clone().unwrap_or_default(), facet_limit))
+        }
+        "html" => Box::new(schema::HtmlFormatter::new(writer)),
+        #[cfg(feature = "sqlite")]
+        "sqlite" => {
+            let db_path = output_path
+                .map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "gkg-export.sqlite".to_string());
+            Box::new(crate::export::sqlite::SqliteFormatter::new(db_path))
+        }
         _ => Box::new(json::JsonFormatter::new(writer, format != "json-compact", fields.clone())),
     }
 }
@@ -0,0 +1,118 @@
+use std::io::Write;
+
+use serde::Serialize;
+
+use super::OutputFormatter;
+use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoredRecord};
+
+/// Emits each record as a length-delimited MessagePack value: a little-endian
+/// `u32` byte length followed by the encoded bytes, so a reader can pull
+/// records one at a time without scanning for a text delimiter.
+pub struct MsgpackFormatter {
+    writer: Box<dyn Write>,
+}
+
+impl MsgpackFormatter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self { writer }
+    }
+
+    fn write_framed<T: Serialize>(&mut self, value: &T) -> Result<(), NewsfreshError> {
+        let bytes = rmp_serde::to_vec(value)
+            .map_err(|e| NewsfreshError::Other(format!("MessagePack encode error: {e}")))?;
+        self.writer.write_all(&(bytes.len() as u32).to_le_bytes())?;
+        self.writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+impl OutputFormatter for MsgpackFormatter {
+    fn begin(&mut self) -> Result<(), NewsfreshError> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &GkgRecord) -> Result<(), NewsfreshError> {
+        self.write_framed(record)
+    }
+
+    fn write_scored_record(&mut self, scored: &ScoredRecord) -> Result<(), NewsfreshError> {
+        self.write_framed(scored)
+    }
+
+    fn finish(&mut self) -> Result<(), NewsfreshError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::model::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn make_test_record() -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "20250217-1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec!["LEADER".into()],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec!["donald trump".into()],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn frames_are_length_prefixed() {
+        let record = make_test_record();
+        let shared = SharedBuf::default();
+        let mut fmt = MsgpackFormatter::new(Box::new(shared.clone()));
+        fmt.begin().unwrap();
+        fmt.write_record(&record).unwrap();
+        fmt.finish().unwrap();
+
+        let buf = shared.0.lock().unwrap().clone();
+        let len = u32::from_le_bytes(buf[0..4].try_into().unwrap()) as usize;
+        assert_eq!(buf.len(), 4 + len);
+
+        let decoded: GkgRecord = rmp_serde::from_slice(&buf[4..]).unwrap();
+        assert_eq!(decoded.gkg_record_id, "20250217-1");
+    }
+}
@@ -0,0 +1,197 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow::array::{Float64Array, Int64Array, ListArray, StringArray, StringBuilder};
+use arrow::array::builder::ListBuilder;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+
+use super::OutputFormatter;
+use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoredRecord};
+
+/// Buffers records in `write_record` and flushes one typed Parquet table in
+/// `finish` — persons/organizations/themes as string lists, tone as f64, the
+/// GKG timestamp as an i64 — matching the `OutputFormatter` batch lifecycle
+/// rather than streaming row-by-row.
+pub struct ParquetFormatter {
+    writer: Option<Box<dyn Write>>,
+    records: Vec<GkgRecord>,
+}
+
+impl ParquetFormatter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer: Some(writer),
+            records: Vec::new(),
+        }
+    }
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("gkg_record_id", DataType::Utf8, false),
+            Field::new("date", DataType::Int64, false),
+            Field::new("source_common_name", DataType::Utf8, false),
+            Field::new("document_identifier", DataType::Utf8, false),
+            Field::new(
+                "persons",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+            Field::new(
+                "organizations",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+            Field::new(
+                "themes",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+            Field::new("tone", DataType::Float64, true),
+        ])
+    }
+
+    fn to_batch(&self) -> Result<RecordBatch, NewsfreshError> {
+        let ids: StringArray = self.records.iter().map(|r| Some(r.gkg_record_id.as_str())).collect();
+        let dates: Int64Array = self.records.iter().map(|r| Some(r.date)).collect();
+        let sources: StringArray =
+            self.records.iter().map(|r| Some(r.source_common_name.as_str())).collect();
+        let document_ids: StringArray =
+            self.records.iter().map(|r| Some(r.document_identifier.as_str())).collect();
+        let persons = string_list_array(self.records.iter().map(|r| &r.v1_persons));
+        let organizations = string_list_array(self.records.iter().map(|r| &r.v1_organizations));
+        let themes = string_list_array(self.records.iter().map(|r| &r.v1_themes));
+        let tones: Float64Array = self
+            .records
+            .iter()
+            .map(|r| r.tone.as_ref().map(|t| t.tone))
+            .collect();
+
+        RecordBatch::try_new(
+            Arc::new(Self::schema()),
+            vec![
+                Arc::new(ids),
+                Arc::new(dates),
+                Arc::new(sources),
+                Arc::new(document_ids),
+                Arc::new(persons),
+                Arc::new(organizations),
+                Arc::new(themes),
+                Arc::new(tones),
+            ],
+        )
+        .map_err(|e| NewsfreshError::Other(format!("Failed to build record batch: {e}")))
+    }
+}
+
+fn string_list_array<'a, I>(rows: I) -> ListArray
+where
+    I: Iterator<Item = &'a Vec<String>>,
+{
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for row in rows {
+        for value in row {
+            builder.values().append_value(value);
+        }
+        builder.append(true);
+    }
+    builder.finish()
+}
+
+impl OutputFormatter for ParquetFormatter {
+    fn begin(&mut self) -> Result<(), NewsfreshError> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &GkgRecord) -> Result<(), NewsfreshError> {
+        self.records.push(record.clone());
+        Ok(())
+    }
+
+    fn write_scored_record(&mut self, scored: &ScoredRecord) -> Result<(), NewsfreshError> {
+        self.write_record(&scored.record)
+    }
+
+    fn finish(&mut self) -> Result<(), NewsfreshError> {
+        let writer = self
+            .writer
+            .take()
+            .ok_or_else(|| NewsfreshError::Other("Parquet formatter already finished".into()))?;
+        let batch = self.to_batch()?;
+
+        let mut arrow_writer = ArrowWriter::try_new(writer, batch.schema(), None)
+            .map_err(|e| NewsfreshError::Other(format!("Failed to create Parquet writer: {e}")))?;
+        arrow_writer
+            .write(&batch)
+            .map_err(|e| NewsfreshError::Other(format!("Failed to write Parquet batch: {e}")))?;
+        arrow_writer
+            .close()
+            .map_err(|e| NewsfreshError::Other(format!("Failed to close Parquet writer: {e}")))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_test_record() -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "20250217-1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec!["LEADER".into()],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec!["donald trump".into()],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: Some(Tone {
+                tone: -1.5,
+                positive_score: 2.0,
+                negative_score: 3.5,
+                polarity: 5.5,
+                activity_ref_density: 10.0,
+                self_group_ref_density: 0.5,
+                word_count: 500,
+            }),
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn batch_has_one_row_per_record() {
+        let mut fmt = ParquetFormatter::new(Box::new(Vec::new()));
+        fmt.begin().unwrap();
+        fmt.write_record(&make_test_record()).unwrap();
+        let batch = fmt.to_batch().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+    }
+
+    #[test]
+    fn finish_flushes_without_error() {
+        let mut fmt = ParquetFormatter::new(Box::new(Vec::new()));
+        fmt.begin().unwrap();
+        fmt.write_record(&make_test_record()).unwrap();
+        fmt.finish().unwrap();
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::BTreeSet;
 use std::io::Write;
 
+use super::OutputFormatter;
 use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoredRecord};
+use crate::search::themes::canonicalize_theme;
 
 pub fn print_tealeaf_schema(writer: &mut dyn Write) -> Result<(), NewsfreshError> {
     writeln!(writer, "# GDELT GKG v2.1 TeaLeaf Schema")?;
@@ -97,9 +101,226 @@ pub fn print_json_schema(writer: &mut dyn Write) -> Result<(), NewsfreshError> {
     Ok(())
 }
 
+/// Escapes the five HTML-significant characters so record content (an
+/// article title, a quote, a theme code) can never break out of the markup
+/// it's interpolated into.
+fn escape_html(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Names a tone's sign/magnitude bucket as a CSS class. Shares
+/// `aggregate::tone_bucket`'s ±5 boundaries for the very-negative and
+/// very-positive classes, but splits the rest at zero: only a tone of
+/// exactly `0.0` is neutral, where `tone_bucket` treats all of (-1, 1) as
+/// neutral.
+fn tone_badge_class(tone: f64) -> &'static str {
+    if tone <= -5.0 {
+        "tone-very-negative"
+    } else if tone < 0.0 {
+        "tone-negative"
+    } else if tone == 0.0 {
+        "tone-neutral"
+    } else if tone < 5.0 {
+        "tone-positive"
+    } else {
+        "tone-very-positive"
+    }
+}
+
+const HTML_REPORT_STYLE: &str = "
+body { font-family: sans-serif; background: #f4f4f6; margin: 2rem; }
+.card { background: #fff; border-radius: 8px; padding: 1rem 1.5rem; margin-bottom: 1rem; box-shadow: 0 1px 3px rgba(0,0,0,0.15); }
+.card h2 { margin: 0 0 0.25rem; font-size: 1.1rem; }
+.card .byline { color: #666; font-size: 0.85rem; margin-bottom: 0.5rem; }
+.card img.thumbnail { max-width: 160px; max-height: 120px; float: right; border-radius: 4px; margin-left: 1rem; }
+.badge { display: inline-block; border-radius: 4px; padding: 0.1rem 0.5rem; font-size: 0.8rem; color: #fff; margin-right: 0.5rem; }
+.tone-very-negative { background: #8b0000; }
+.tone-negative { background: #e06666; }
+.tone-neutral { background: #999; }
+.tone-positive { background: #6aa84f; }
+.tone-very-positive { background: #274e13; }
+.chip { display: inline-block; background: #e8eaf6; color: #333; border-radius: 12px; padding: 0.1rem 0.6rem; font-size: 0.8rem; margin: 0.1rem; }
+.entities { margin: 0.4rem 0; }
+.entities .label { font-weight: bold; margin-right: 0.3rem; }
+.quotations { margin-top: 0.5rem; font-style: italic; color: #444; }
+.relevance { color: #666; font-size: 0.8rem; }
+";
+
+/// Renders `records` as a self-contained HTML report: one card per record
+/// with a headline link, tone badge, canonicalized theme chips, entity
+/// lists, and top quotations. Sorts by descending `relevance_score` first
+/// (so a search/ranking pipeline's output reads top-to-bottom), and escapes
+/// every interpolated string to rule out markup injection from source
+/// content.
+pub fn print_html_report(records: &[ScoredRecord], writer: &mut dyn Write) -> Result<(), NewsfreshError> {
+    let mut ordered: Vec<&ScoredRecord> = records.iter().collect();
+    ordered.sort_by(|a, b| {
+        b.relevance_score.partial_cmp(&a.relevance_score).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html lang=\"en\">")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(writer, "<title>NewsFresh Report</title>")?;
+    writeln!(writer, "<style>{HTML_REPORT_STYLE}</style>")?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+
+    for scored in &ordered {
+        let record = &scored.record;
+        writeln!(writer, "<div class=\"card\">")?;
+
+        if let Some(image) = &record.sharing_image {
+            writeln!(
+                writer,
+                "<img class=\"thumbnail\" src=\"{}\" alt=\"\">",
+                escape_html(image)
+            )?;
+        }
+
+        writeln!(
+            writer,
+            "<h2><a href=\"{url}\">{url}</a></h2>",
+            url = escape_html(&record.document_identifier)
+        )?;
+        writeln!(writer, "<div class=\"byline\">{}</div>", escape_html(&record.source_common_name))?;
+
+        if let Some(tone) = &record.tone {
+            writeln!(
+                writer,
+                "<span class=\"badge {}\">tone {:.1}</span>",
+                tone_badge_class(tone.tone),
+                tone.tone
+            )?;
+        }
+        if scored.relevance_score > 0.0 {
+            writeln!(
+                writer,
+                "<span class=\"relevance\">relevance {:.3}</span>",
+                scored.relevance_score
+            )?;
+        }
+
+        let themes: BTreeSet<String> = record
+            .v1_themes
+            .iter()
+            .map(|t| canonicalize_theme(t))
+            .chain(record.v2_enhanced_themes.iter().map(|t| canonicalize_theme(&t.theme)))
+            .collect();
+        if !themes.is_empty() {
+            write!(writer, "<div class=\"entities\">")?;
+            for theme in &themes {
+                write!(writer, "<span class=\"chip\">{}</span>", escape_html(theme))?;
+            }
+            writeln!(writer, "</div>")?;
+        }
+
+        let persons: BTreeSet<&str> = record
+            .v1_persons
+            .iter()
+            .map(String::as_str)
+            .chain(record.v2_enhanced_persons.iter().map(|p| p.name.as_str()))
+            .collect();
+        if !persons.is_empty() {
+            writeln!(
+                writer,
+                "<div class=\"entities\"><span class=\"label\">Persons:</span> {}</div>",
+                escape_html(&persons.into_iter().collect::<Vec<_>>().join(", "))
+            )?;
+        }
+
+        let organizations: BTreeSet<&str> = record
+            .v1_organizations
+            .iter()
+            .map(String::as_str)
+            .chain(record.v2_enhanced_organizations.iter().map(|o| o.name.as_str()))
+            .collect();
+        if !organizations.is_empty() {
+            writeln!(
+                writer,
+                "<div class=\"entities\"><span class=\"label\">Organizations:</span> {}</div>",
+                escape_html(&organizations.into_iter().collect::<Vec<_>>().join(", "))
+            )?;
+        }
+
+        if !record.quotations.is_empty() {
+            writeln!(writer, "<div class=\"quotations\">")?;
+            for quote in record.quotations.iter().take(3) {
+                writeln!(writer, "<p>\"{}\"</p>", escape_html(&quote.quote))?;
+            }
+            writeln!(writer, "</div>")?;
+        }
+
+        writeln!(writer, "</div>")?;
+    }
+
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")?;
+    Ok(())
+}
+
+/// Buffers records in `write_record`/`write_scored_record` and flushes one
+/// [`print_html_report`] document in `finish`, matching the `OutputFormatter`
+/// batch lifecycle [`super::parquet::ParquetFormatter`] uses rather than
+/// streaming row-by-row (an HTML report needs the full set to sort by
+/// relevance and wrap in a single `<html>` document).
+pub struct HtmlFormatter {
+    writer: Box<dyn Write>,
+    records: Vec<ScoredRecord>,
+}
+
+impl HtmlFormatter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer,
+            records: Vec::new(),
+        }
+    }
+}
+
+impl OutputFormatter for HtmlFormatter {
+    fn begin(&mut self) -> Result<(), NewsfreshError> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &GkgRecord) -> Result<(), NewsfreshError> {
+        self.records.push(ScoredRecord {
+            relevance_score: 0.0,
+            snippet: None,
+            score_details: vec![],
+            record: record.clone(),
+        });
+        Ok(())
+    }
+
+    fn write_scored_record(&mut self, scored: &ScoredRecord) -> Result<(), NewsfreshError> {
+        self.records.push(scored.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), NewsfreshError> {
+        print_html_report(&self.records, &mut self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::*;
 
     #[test]
     fn test_print_tealeaf_schema_writes_output() {
@@ -114,4 +335,102 @@ mod tests {
         print_json_schema(&mut buf).unwrap();
         assert!(!buf.is_empty());
     }
+
+    fn make_test_record() -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "1".into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "nytimes.com".into(),
+            document_identifier: "https://nytimes.com/article".into(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec!["TAX_FNCACT_PRESIDENT".into()],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec!["<script>alert(1)</script>".into()],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec!["congress".into()],
+            v2_enhanced_organizations: vec![],
+            tone: Some(Tone {
+                tone: -6.0,
+                positive_score: 1.0,
+                negative_score: 7.0,
+                polarity: 8.0,
+                activity_ref_density: 1.0,
+                self_group_ref_density: 1.0,
+                word_count: 100,
+            }),
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: Some("https://img.example.com/photo.jpg".into()),
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![Quotation {
+                offset: 0,
+                length: 10,
+                verb: "said".into(),
+                quote: "\"hello\" & <b>world</b>".into(),
+            }],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn escape_html_escapes_all_five_special_characters() {
+        assert_eq!(escape_html("<a href=\"x\">&'</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&#39;&lt;/a&gt;");
+    }
+
+    #[test]
+    fn tone_badge_class_follows_sign_and_magnitude() {
+        assert_eq!(tone_badge_class(-6.0), "tone-very-negative");
+        assert_eq!(tone_badge_class(-2.0), "tone-negative");
+        assert_eq!(tone_badge_class(0.0), "tone-neutral");
+        assert_eq!(tone_badge_class(2.0), "tone-positive");
+        assert_eq!(tone_badge_class(6.0), "tone-very-positive");
+    }
+
+    #[test]
+    fn print_html_report_escapes_record_content() {
+        let scored = ScoredRecord {
+            relevance_score: 0.0,
+            snippet: None,
+            score_details: vec![],
+            record: make_test_record(),
+        };
+        let mut buf: Vec<u8> = Vec::new();
+        print_html_report(&[scored], &mut buf).unwrap();
+        let html = String::from_utf8(buf).unwrap();
+
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("&lt;b&gt;world&lt;/b&gt;"));
+        assert!(html.contains("PRESIDENT"));
+        assert!(html.contains("tone-very-negative"));
+    }
+
+    #[test]
+    fn print_html_report_sorts_by_descending_relevance() {
+        let mut low = make_test_record();
+        low.document_identifier = "https://example.com/low".into();
+        let mut high = make_test_record();
+        high.document_identifier = "https://example.com/high".into();
+
+        let scored = vec![
+            ScoredRecord { relevance_score: 0.1, snippet: None, score_details: vec![], record: low },
+            ScoredRecord { relevance_score: 9.5, snippet: None, score_details: vec![], record: high },
+        ];
+        let mut buf: Vec<u8> = Vec::new();
+        print_html_report(&scored, &mut buf).unwrap();
+        let html = String::from_utf8(buf).unwrap();
+
+        let high_pos = html.find("example.com/high").unwrap();
+        let low_pos = html.find("example.com/low").unwrap();
+        assert!(high_pos < low_pos);
+    }
 }
@@ -186,8 +186,14 @@ fn build_schemas() -> Vec<Schema> {
             .field("amounts", FieldType::new("amount_entry").array())
             .field("translation_info", FieldType::new("translation_info").nullable())
             .field("extras_xml", FieldType::new("string").nullable()),
+        Schema::new("score_detail")
+            .field("rule", FieldType::new("string"))
+            .field("score", FieldType::new("float").nullable())
+            .field("similarity", FieldType::new("float").nullable()),
         Schema::new("scored_gkg_record")
             .field("relevance_score", FieldType::new("float"))
+            .field("snippet", FieldType::new("string").nullable())
+            .field("score_details", FieldType::new("score_detail").array())
             .field("gkg_record_id", FieldType::new("string"))
             .field("date", FieldType::new("int"))
             .field("source_collection_id", FieldType::new("int"))
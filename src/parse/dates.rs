@@ -1,6 +1,13 @@
 use crate::model::date::EnhancedDate;
 use super::delimiters::{parse_i32, parse_i64};
 
+/// Resolution codes carried on [`EnhancedDate`]: `1` is a fully resolved
+/// day, `2` is month-level (the day is unspecified), and `3` is year-level
+/// (month and day are both unspecified).
+const RESOLUTION_DAY: i32 = 1;
+const RESOLUTION_MONTH: i32 = 2;
+const RESOLUTION_YEAR: i32 = 3;
+
 pub fn parse_enhanced_dates(field: &str) -> Vec<EnhancedDate> {
     if field.is_empty() {
         return Vec::new();
@@ -24,6 +31,65 @@ pub fn parse_enhanced_dates(field: &str) -> Vec<EnhancedDate> {
         .collect()
 }
 
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 0,
+    }
+}
+
+fn ymd(year: i32, month: i32, day: i32) -> i64 {
+    year as i64 * 10_000 + month as i64 * 100 + day as i64
+}
+
+/// Normalizes an [`EnhancedDate`] into an inclusive `[earliest, latest]`
+/// YYYYMMDD interval, honoring `resolution` rather than treating an
+/// unspecified `month`/`day` as literal zero: year-level dates span the
+/// whole year, month-level dates span the first through last day of the
+/// month (leap-year aware), and day-level dates collapse to a single day.
+/// Returns `None` when the date can't be normalized (a zero/negative year,
+/// or a month/day outside its valid range).
+pub fn to_date_range(d: &EnhancedDate) -> Option<(i64, i64)> {
+    if d.year <= 0 {
+        return None;
+    }
+
+    match d.resolution {
+        RESOLUTION_YEAR => Some((ymd(d.year, 1, 1), ymd(d.year, 12, 31))),
+        RESOLUTION_MONTH => {
+            if !(1..=12).contains(&d.month) {
+                return None;
+            }
+            let last_day = days_in_month(d.year, d.month);
+            Some((ymd(d.year, d.month, 1), ymd(d.year, d.month, last_day)))
+        }
+        RESOLUTION_DAY => {
+            if !(1..=12).contains(&d.month) {
+                return None;
+            }
+            let last_day = days_in_month(d.year, d.month);
+            if d.day < 1 || d.day > last_day {
+                return None;
+            }
+            let exact = ymd(d.year, d.month, d.day);
+            Some((exact, exact))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +122,53 @@ mod tests {
         assert_eq!(result[1].day, 0);
         assert_eq!(result[1].char_offset, 480);
     }
+
+    fn make_date(resolution: i32, month: i32, day: i32, year: i32) -> EnhancedDate {
+        EnhancedDate { resolution, month, day, year, char_offset: 0 }
+    }
+
+    #[test]
+    fn to_date_range_day_resolution_collapses_to_a_single_day() {
+        let range = to_date_range(&make_date(RESOLUTION_DAY, 3, 15, 2024)).unwrap();
+        assert_eq!(range, (20240315, 20240315));
+    }
+
+    #[test]
+    fn to_date_range_month_resolution_spans_first_to_last_day() {
+        let range = to_date_range(&make_date(RESOLUTION_MONTH, 6, 0, 2023)).unwrap();
+        assert_eq!(range, (20230601, 20230630));
+    }
+
+    #[test]
+    fn to_date_range_month_resolution_handles_leap_february() {
+        let range = to_date_range(&make_date(RESOLUTION_MONTH, 2, 0, 2024)).unwrap();
+        assert_eq!(range, (20240201, 20240229));
+    }
+
+    #[test]
+    fn to_date_range_month_resolution_handles_non_leap_february() {
+        let range = to_date_range(&make_date(RESOLUTION_MONTH, 2, 0, 2023)).unwrap();
+        assert_eq!(range, (20230201, 20230228));
+    }
+
+    #[test]
+    fn to_date_range_year_resolution_spans_the_whole_year() {
+        let range = to_date_range(&make_date(RESOLUTION_YEAR, 0, 0, 2022)).unwrap();
+        assert_eq!(range, (20220101, 20221231));
+    }
+
+    #[test]
+    fn to_date_range_rejects_zero_year() {
+        assert!(to_date_range(&make_date(RESOLUTION_YEAR, 0, 0, 0)).is_none());
+    }
+
+    #[test]
+    fn to_date_range_rejects_out_of_range_day() {
+        assert!(to_date_range(&make_date(RESOLUTION_DAY, 4, 31, 2024)).is_none());
+    }
+
+    #[test]
+    fn to_date_range_rejects_unknown_resolution() {
+        assert!(to_date_range(&make_date(9, 3, 15, 2024)).is_none());
+    }
 }
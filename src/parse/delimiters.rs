@@ -47,6 +47,24 @@ pub fn parse_i32(s: &str) -> i32 {
     s.parse::<i32>().unwrap_or(0)
 }
 
+/// Normalizes `input` for fuzzy entity/theme comparisons: lowercases,
+/// applies Unicode NFD decomposition to split accented letters from their
+/// combining marks, strips the combining marks, and transliterates any
+/// remaining non-ASCII letters to their closest ASCII form. This folds
+/// GDELT's multilingual entity names ("José", "Zürich") to the same
+/// canonical key as their unaccented ASCII spellings ("jose", "zurich"),
+/// mirroring the normalization step search engines apply during tokenization.
+pub fn normalize(input: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    let decomposed: String = input.nfd().filter(|c| !is_combining_mark(*c)).collect();
+    deunicode::deunicode(&decomposed).to_lowercase()
+}
+
+fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32, 0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +120,20 @@ mod tests {
         assert_eq!(parse_i32("100"), 100);
         assert_eq!(parse_i32("xyz"), 0);
     }
+
+    #[test]
+    fn test_normalize_strips_diacritics() {
+        assert_eq!(normalize("José"), "jose");
+        assert_eq!(normalize("Zürich"), "zurich");
+    }
+
+    #[test]
+    fn test_normalize_lowercases() {
+        assert_eq!(normalize("TRUMP"), "trump");
+    }
+
+    #[test]
+    fn test_normalize_matches_plain_ascii_equivalent() {
+        assert_eq!(normalize("José"), normalize("jose"));
+    }
 }
@@ -1,9 +1,10 @@
 pub mod delimiters;
 pub mod reader;
+pub mod stream;
 
 mod amounts;
 mod counts;
-mod dates;
+pub(crate) mod dates;
 mod gcam;
 mod locations;
 mod names;
@@ -17,6 +18,7 @@ use crate::error::NewsfreshError;
 use crate::model::GkgRecord;
 
 pub use reader::GkgReader;
+pub use stream::{MalformedLine, MultiGkgReader};
 
 pub fn parse_record(line: &str, line_number: usize) -> Result<GkgRecord, NewsfreshError> {
     let fields: Vec<&str> = line.split('\t').collect();
@@ -0,0 +1,245 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use crate::error::NewsfreshError;
+use crate::fetch::decompress;
+use crate::model::GkgRecord;
+
+use super::parse_record;
+use super::reader::GkgReader;
+
+/// One CSV source discovered under a starting path: either a bare `.csv`
+/// file or a single `.csv` entry living inside a `.zip` archive.
+enum Source {
+    Csv(PathBuf),
+    ZipEntry(PathBuf, String),
+}
+
+fn source_path(source: &Source) -> PathBuf {
+    match source {
+        Source::Csv(path) => path.clone(),
+        Source::ZipEntry(path, _) => path.clone(),
+    }
+}
+
+fn open_source(source: &Source) -> Result<Box<dyn BufRead>, NewsfreshError> {
+    match source {
+        Source::Csv(path) => {
+            let file = std::fs::File::open(path)?;
+            Ok(Box::new(std::io::BufReader::new(file)))
+        }
+        Source::ZipEntry(zip_path, entry_name) => decompress::stream_gkg_zip_entry(zip_path, entry_name),
+    }
+}
+
+/// Discovers every `.csv` source reachable from `path`: the file itself if
+/// it's a bare `.csv`, every `.csv` entry inside it (in archive order) if
+/// it's a `.zip`, or every `.csv`/`.zip` found by walking a directory
+/// (recursively, in sorted order) — e.g. a day's worth of 15-minute GKG
+/// pulls. This only reads zip central directories and directory listings,
+/// never an entry's contents, so it stays cheap regardless of how much data
+/// it ultimately points at.
+fn discover_sources(path: &Path) -> Result<Vec<Source>, NewsfreshError> {
+    if path.is_dir() {
+        let mut children: Vec<PathBuf> =
+            std::fs::read_dir(path)?.filter_map(|entry| entry.ok().map(|e| e.path())).collect();
+        children.sort();
+
+        let mut sources = Vec::new();
+        for child in children {
+            sources.extend(discover_sources(&child)?);
+        }
+        return Ok(sources);
+    }
+
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("zip") => Ok(decompress::list_gkg_zip_entries(path)?
+            .into_iter()
+            .map(|name| Source::ZipEntry(path.to_path_buf(), name))
+            .collect()),
+        Some("csv") => Ok(vec![Source::Csv(path.to_path_buf())]),
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// A line that failed to parse into a [`GkgRecord`], surfaced through
+/// [`MultiGkgReader::malformed_lines`] in tolerant mode instead of ending
+/// iteration.
+#[derive(Debug, Clone)]
+pub struct MalformedLine {
+    pub source: PathBuf,
+    pub line_number: usize,
+    pub error: String,
+}
+
+/// Lazily yields every [`GkgRecord`] reachable from a starting path — a bare
+/// `.csv` file, every `.csv` entry in a `.zip` archive, or every archive/CSV
+/// in a directory of them — without ever materializing more than one entry's
+/// decompressed contents at a time. Lets a caller `build` a search index or
+/// stream into a SQLite export incrementally over a day's worth of
+/// 15-minute files instead of collecting them all into memory first.
+///
+/// By default a malformed line ends iteration with `Err`, matching
+/// [`super::parse_record`]'s own per-line error; call [`Self::tolerant`] to
+/// count and record malformed lines instead (real GDELT dumps occasionally
+/// contain bad rows).
+pub struct MultiGkgReader {
+    pending: VecDeque<Source>,
+    current: Option<(PathBuf, GkgReader<Box<dyn BufRead>>)>,
+    tolerant: bool,
+    malformed: Vec<MalformedLine>,
+}
+
+impl MultiGkgReader {
+    /// Discovers every CSV source reachable from `path` (see
+    /// [`discover_sources`]) and prepares to stream them in order.
+    pub fn open(path: &Path) -> Result<Self, NewsfreshError> {
+        Ok(Self {
+            pending: discover_sources(path)?.into(),
+            current: None,
+            tolerant: false,
+            malformed: Vec::new(),
+        })
+    }
+
+    /// Switches to tolerant mode: a line that fails to parse is counted and
+    /// recorded in [`Self::malformed_lines`] instead of ending iteration.
+    pub fn tolerant(mut self) -> Self {
+        self.tolerant = true;
+        self
+    }
+
+    /// Every malformed line collected so far in tolerant mode. Always empty
+    /// unless [`Self::tolerant`] was called.
+    pub fn malformed_lines(&self) -> &[MalformedLine] {
+        &self.malformed
+    }
+
+    fn advance_source(&mut self) -> Result<bool, NewsfreshError> {
+        match self.pending.pop_front() {
+            Some(source) => {
+                let path = source_path(&source);
+                let reader = open_source(&source)?;
+                self.current = Some((path, GkgReader::new(reader)));
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl Iterator for MultiGkgReader {
+    type Item = Result<GkgRecord, NewsfreshError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                match self.advance_source() {
+                    Ok(true) => {}
+                    Ok(false) => return None,
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+
+            let (path, reader) = self.current.as_mut().expect("just ensured current is Some");
+            match reader.next() {
+                Some(Ok((line_number, line))) => match parse_record(&line, line_number) {
+                    Ok(record) => return Some(Ok(record)),
+                    Err(e) if self.tolerant => {
+                        self.malformed.push(MalformedLine {
+                            source: path.clone(),
+                            line_number,
+                            error: e.to_string(),
+                        });
+                    }
+                    Err(e) => return Some(Err(e)),
+                },
+                Some(Err(e)) if self.tolerant => {
+                    self.malformed.push(MalformedLine { source: path.clone(), line_number: 0, error: e.to_string() });
+                    self.current = None;
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.current = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_csv(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn sample_line(id: &str) -> String {
+        format!("{id}\t20250217120000\t1\tnytimes.com\thttps://nytimes.com/{id}")
+    }
+
+    #[test]
+    fn reads_every_record_from_a_single_csv() {
+        let dir = tempfile::tempdir().unwrap();
+        let contents = format!("{}\n{}\n", sample_line("a"), sample_line("b"));
+        let path = write_csv(dir.path(), "one.csv", &contents);
+
+        let records: Vec<GkgRecord> =
+            MultiGkgReader::open(&path).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].gkg_record_id, "a");
+        assert_eq!(records[1].gkg_record_id, "b");
+    }
+
+    #[test]
+    fn walks_a_directory_of_csv_files_in_sorted_order() {
+        let dir = tempfile::tempdir().unwrap();
+        write_csv(dir.path(), "20250217140000.csv", &format!("{}\n", sample_line("second")));
+        write_csv(dir.path(), "20250217120000.csv", &format!("{}\n", sample_line("first")));
+
+        let records: Vec<GkgRecord> =
+            MultiGkgReader::open(dir.path()).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].gkg_record_id, "first");
+        assert_eq!(records[1].gkg_record_id, "second");
+    }
+
+    #[test]
+    fn strict_mode_aborts_on_a_malformed_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let contents = format!("{}\ntoo\tfew\n{}\n", sample_line("a"), sample_line("b"));
+        let path = write_csv(dir.path(), "one.csv", &contents);
+
+        let mut reader = MultiGkgReader::open(&path).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn tolerant_mode_skips_and_records_malformed_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let contents = format!("{}\ntoo\tfew\n{}\n", sample_line("a"), sample_line("b"));
+        let path = write_csv(dir.path(), "one.csv", &contents);
+
+        let mut reader = MultiGkgReader::open(&path).unwrap().tolerant();
+        let records: Vec<GkgRecord> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].gkg_record_id, "a");
+        assert_eq!(records[1].gkg_record_id, "b");
+        assert_eq!(reader.malformed_lines().len(), 1);
+        assert_eq!(reader.malformed_lines()[0].line_number, 2);
+    }
+
+    #[test]
+    fn empty_directory_yields_no_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let records: Vec<GkgRecord> =
+            MultiGkgReader::open(dir.path()).unwrap().collect::<Result<_, _>>().unwrap();
+        assert!(records.is_empty());
+    }
+}
@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+
+use crate::error::NewsfreshError;
+use crate::model::{GkgRecord, ScoreDetail, ScoredRecord};
+use crate::nlp::{self, Language};
+
+use super::enrich;
+use super::fuzzy::{self, BkTree};
+use super::geo;
+use super::tokenize::{tokenize, tokenize_lang};
+use super::ScoredHit;
+
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+
+/// Score multipliers for the typo-tolerance ladder: an exact hit outranks a
+/// 1-edit hit, which outranks a 2-edit hit.
+const EXACT_WEIGHT: f32 = 1.0;
+const ONE_EDIT_WEIGHT: f32 = 0.5;
+const TWO_EDIT_WEIGHT: f32 = 0.25;
+
+/// A pure-Rust BM25 full-text index over the textual fields of `GkgRecord`.
+///
+/// Unlike [`super::tantivy::TantivyEngine`], this keeps no external index
+/// state — just a postings map plus per-record lengths — which makes the
+/// scoring formula explicit and easy to reason about or tune.
+pub struct Bm25Engine {
+    /// term -> Vec<(record_index, weighted_term_frequency)>
+    postings: HashMap<String, Vec<(usize, f32)>>,
+    doc_lengths: Vec<f32>,
+    avgdl: f32,
+    num_docs: usize,
+    k1: f32,
+    b: f32,
+    /// Whether query terms expand to nearby vocabulary terms (see
+    /// [`fuzzy::BkTree`]); rebuilt from `postings` every `build()`.
+    typo_tolerance: bool,
+    vocab: BkTree,
+    locations: Vec<(usize, f64, f64)>,
+    /// Per-field multipliers applied to term frequencies while indexing (see
+    /// [`FieldWeights`]); defaults to unweighted (all `1.0`).
+    field_weights: FieldWeights,
+}
+
+impl Bm25Engine {
+    pub fn new() -> Self {
+        Self::with_params(K1, B)
+    }
+
+    /// Builds an engine with explicit `k1`/`b` tuning knobs instead of the
+    /// defaults, for callers that expose them (e.g. `--bm25-k1`/`--bm25-b`).
+    pub fn with_params(k1: f32, b: f32) -> Self {
+        Self {
+            postings: HashMap::new(),
+            doc_lengths: Vec::new(),
+            avgdl: 0.0,
+            num_docs: 0,
+            k1,
+            b,
+            typo_tolerance: true,
+            vocab: BkTree::new(),
+            locations: Vec::new(),
+            field_weights: FieldWeights::default(),
+        }
+    }
+
+    /// Toggles typo-tolerant query expansion (on by default). Takes effect
+    /// from the next `build()` onward.
+    pub fn with_typo_tolerance(mut self, on: bool) -> Self {
+        self.typo_tolerance = on;
+        self
+    }
+
+    /// Applies per-field weighting to term frequencies during indexing (see
+    /// [`FieldWeights`]), so e.g. a theme match can be made to outrank an
+    /// incidental match in a less important field. Takes effect from the
+    /// next `build()` onward.
+    pub fn with_field_weights(mut self, weights: FieldWeights) -> Self {
+        self.field_weights = weights;
+        self
+    }
+
+    fn document_text(record: &GkgRecord) -> String {
+        let enriched = enrich::enrich_record(record);
+        [
+            enriched.persons,
+            enriched.organizations,
+            enriched.themes,
+            enriched.locations,
+            enriched.quotations,
+            enriched.names,
+            enriched.source,
+        ]
+        .join(" ")
+    }
+
+    /// Determines which stopword/stemmer pair to index `record` with: the
+    /// language declared in `translation_info` when present, otherwise a
+    /// best-effort guess from the record's own text.
+    fn record_language(record: &GkgRecord, text: &str) -> Language {
+        record
+            .translation_info
+            .as_ref()
+            .map(|t| Language::from_code(&t.source_language))
+            .unwrap_or_else(|| nlp::detect(text))
+    }
+
+    /// Builds `record`'s weighted bag of terms, one field at a time so each
+    /// can carry its own multiplier from `self.field_weights`.
+    fn weighted_term_freqs(&self, record: &GkgRecord, lang: Language) -> HashMap<String, f32> {
+        let enriched = enrich::enrich_record(record);
+        let mut freqs = HashMap::new();
+        let mut accumulate = |text: &str, weight: f32| {
+            for token in tokenize_lang(text, lang) {
+                *freqs.entry(token).or_insert(0.0) += weight;
+            }
+        };
+        accumulate(&enriched.source, self.field_weights.source);
+        accumulate(&enriched.persons, self.field_weights.persons);
+        accumulate(&enriched.organizations, self.field_weights.organizations);
+        accumulate(&enriched.themes, self.field_weights.themes);
+        accumulate(&enriched.locations, self.field_weights.locations);
+        accumulate(&enriched.quotations, self.field_weights.quotations);
+        accumulate(&enriched.names, self.field_weights.names);
+        freqs
+    }
+
+    /// Adds a single record to the index, recomputing `avgdl` incrementally
+    /// so records can be indexed as they stream in from the parser.
+    pub fn add_record(&mut self, record: &GkgRecord) {
+        let idx = self.doc_lengths.len();
+        let text = Self::document_text(record);
+        let lang = Self::record_language(record, &text);
+        let term_freqs = self.weighted_term_freqs(record, lang);
+        let doc_len: f32 = term_freqs.values().sum();
+
+        for (term, freq) in term_freqs {
+            if self.typo_tolerance && !self.postings.contains_key(&term) {
+                self.vocab.insert(&term);
+            }
+            self.postings.entry(term).or_default().push((idx, freq));
+        }
+
+        self.doc_lengths.push(doc_len);
+        self.num_docs += 1;
+        let total_len: f32 = self.doc_lengths.iter().sum();
+        self.avgdl = total_len / self.num_docs as f32;
+    }
+
+    fn idf(&self, term: &str) -> f32 {
+        let df = self.postings.get(term).map(|p| p.len()).unwrap_or(0) as f32;
+        let n = self.num_docs as f32;
+        (((n - df + 0.5) / (df + 0.5)) + 1.0).ln()
+    }
+
+    /// Expands a query term to indexed vocabulary terms within the typo
+    /// ladder's edit-distance bound, paired with a score weight so an exact
+    /// hit outranks a 1-edit hit, which outranks a 2-edit hit. With typo
+    /// tolerance off, only an exact match (if indexed) is returned.
+    fn expand_term(&self, term: &str) -> Vec<(String, f32)> {
+        if !self.typo_tolerance {
+            return if self.postings.contains_key(term) {
+                vec![(term.to_string(), EXACT_WEIGHT)]
+            } else {
+                Vec::new()
+            };
+        }
+
+        let max_distance = fuzzy::max_edit_distance(term.chars().count());
+        self.vocab
+            .find_within(term, max_distance)
+            .into_iter()
+            .map(|(matched, distance)| {
+                let weight = match distance {
+                    0 => EXACT_WEIGHT,
+                    1 => ONE_EDIT_WEIGHT,
+                    _ => TWO_EDIT_WEIGHT,
+                };
+                (matched, weight)
+            })
+            .collect()
+    }
+
+    /// Scores every record containing at least one query term (or, with typo
+    /// tolerance on, a near-miss of one) and returns the top `limit` hits
+    /// sorted by descending BM25 score.
+    pub fn query_hits(&self, query_str: &str, limit: usize) -> Vec<ScoredHit> {
+        let terms = tokenize(query_str);
+        if terms.is_empty() || self.num_docs == 0 || self.avgdl == 0.0 {
+            return Vec::new();
+        }
+
+        let mut scores: HashMap<usize, f32> = HashMap::new();
+        for term in &terms {
+            for (matched_term, weight) in self.expand_term(term) {
+                let Some(postings) = self.postings.get(&matched_term) else {
+                    continue;
+                };
+                let idf = self.idf(&matched_term);
+                for &(doc_idx, tf) in postings {
+                    let dl = self.doc_lengths[doc_idx];
+                    let denom = tf + self.k1 * (1.0 - self.b + self.b * (dl / self.avgdl));
+                    let score = weight * idf * (tf * (self.k1 + 1.0)) / denom;
+                    *scores.entry(doc_idx).or_insert(0.0) += score;
+                }
+            }
+        }
+
+        let mut hits: Vec<ScoredHit> = scores
+            .into_iter()
+            .map(|(record_index, score)| ScoredHit { record_index, score })
+            .collect();
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        hits.truncate(limit);
+        hits
+    }
+
+    /// Convenience wrapper that runs [`Self::query_hits`] and resolves the
+    /// hits back into [`ScoredRecord`]s against the original record slice.
+    pub fn query(&self, records: &[GkgRecord], query_str: &str, limit: usize) -> Vec<ScoredRecord> {
+        self.query_hits(query_str, limit)
+            .into_iter()
+            .filter_map(|hit| {
+                records.get(hit.record_index).map(|record| ScoredRecord {
+                    relevance_score: hit.score,
+                    snippet: None,
+                    score_details: vec![ScoreDetail::score("keyword", hit.score)],
+                    record: record.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for Bm25Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::SearchEngine for Bm25Engine {
+    fn build(&mut self, records: &[GkgRecord]) -> Result<(), NewsfreshError> {
+        *self = Self::with_params(self.k1, self.b)
+            .with_typo_tolerance(self.typo_tolerance)
+            .with_field_weights(self.field_weights.clone());
+        for record in records {
+            self.add_record(record);
+        }
+        self.locations = geo::index_locations(records);
+        Ok(())
+    }
+
+    fn search(&self, query_str: &str, limit: usize) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        Ok(self.query_hits(query_str, limit))
+    }
+
+    fn search_geo(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_km: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        Ok(geo::search_radius(&self.locations, lat, lon, radius_km, limit))
+    }
+
+    fn search_geo_bbox(
+        &self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        Ok(geo::search_bbox(&self.locations, min_lat, max_lat, min_lon, max_lon, limit))
+    }
+}
+
+/// Per-field multiplicative weights applied to term frequencies while
+/// indexing (see [`Bm25Engine::with_field_weights`]), so a match in a more
+/// semantically important field (e.g. a theme) can be made to outrank an
+/// incidental match in a less important one (e.g. a source name). Defaults
+/// to unweighted (`1.0` for every field).
+#[derive(Debug, Clone)]
+pub struct FieldWeights {
+    pub source: f32,
+    pub persons: f32,
+    pub organizations: f32,
+    pub themes: f32,
+    pub locations: f32,
+    pub quotations: f32,
+    pub names: f32,
+}
+
+impl Default for FieldWeights {
+    fn default() -> Self {
+        Self {
+            source: 1.0,
+            persons: 1.0,
+            organizations: 1.0,
+            themes: 1.0,
+            locations: 1.0,
+            quotations: 1.0,
+            names: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_record(id: &str, persons: Vec<&str>, themes: Vec<&str>) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: id.into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "example.com".into(),
+            document_identifier: format!("https://example.com/{id}"),
+            v1_persons: persons.into_iter().map(String::from).collect(),
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            v1_themes: themes.into_iter().map(String::from).collect(),
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            tone: None,
+            quotations: vec![],
+            sharing_image: None,
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn ranks_more_relevant_record_higher() {
+        let records = vec![
+            make_record("1", vec!["donald trump"], vec!["ELECTION"]),
+            make_record("2", vec!["donald trump", "donald trump"], vec!["ELECTION", "ELECTION"]),
+        ];
+        let mut engine = Bm25Engine::new();
+        for r in &records {
+            engine.add_record(r);
+        }
+        let hits = engine.query_hits("trump election", 10);
+        assert!(!hits.is_empty());
+        assert_eq!(hits[0].record_index, 1);
+    }
+
+    #[test]
+    fn empty_query_returns_no_hits() {
+        let engine = Bm25Engine::new();
+        assert!(engine.query_hits("", 10).is_empty());
+        assert!(engine.query_hits("the and of", 10).is_empty());
+    }
+
+    #[test]
+    fn custom_params_change_the_score() {
+        let records = vec![make_record("1", vec!["donald trump"], vec!["ELECTION"])];
+        let mut default_engine = Bm25Engine::new();
+        let mut tuned_engine = Bm25Engine::with_params(0.2, 0.0);
+        for r in &records {
+            default_engine.add_record(r);
+            tuned_engine.add_record(r);
+        }
+        let default_score = default_engine.query_hits("trump", 10)[0].score;
+        let tuned_score = tuned_engine.query_hits("trump", 10)[0].score;
+        assert_ne!(default_score, tuned_score);
+    }
+
+    #[test]
+    fn typo_tolerant_query_finds_near_miss_spelling() {
+        let records = vec![make_record("1", vec!["volodymyr zelenskyy"], vec![])];
+        let mut engine = Bm25Engine::new();
+        for r in &records {
+            engine.add_record(r);
+        }
+        assert!(!engine.query_hits("zelensky", 10).is_empty());
+    }
+
+    #[test]
+    fn typo_tolerance_off_requires_exact_match() {
+        let records = vec![make_record("1", vec!["volodymyr zelenskyy"], vec![])];
+        let mut engine = Bm25Engine::new().with_typo_tolerance(false);
+        for r in &records {
+            engine.add_record(r);
+        }
+        assert!(engine.query_hits("zelensky", 10).is_empty());
+        assert!(!engine.query_hits("zelenskyy", 10).is_empty());
+    }
+
+    #[test]
+    fn query_resolves_to_scored_records() {
+        let records = vec![make_record("1", vec!["elon musk"], vec![])];
+        let mut engine = Bm25Engine::new();
+        for r in &records {
+            engine.add_record(r);
+        }
+        let scored = engine.query(&records, "musk", 10);
+        assert_eq!(scored.len(), 1);
+        assert_eq!(scored[0].record.gkg_record_id, "1");
+    }
+
+    // ---- FieldWeights ----
+
+    #[test]
+    fn field_weights_default_is_unweighted() {
+        let weights = FieldWeights::default();
+        assert_eq!(weights.source, 1.0);
+        assert_eq!(weights.themes, 1.0);
+    }
+
+    #[test]
+    fn heavy_theme_weight_outranks_person_match() {
+        let records = vec![
+            make_record("1", vec!["zelenskyy"], vec![]),
+            make_record("2", vec![], vec!["ZELENSKYY"]),
+        ];
+        let heavy_theme_weights = FieldWeights { themes: 10.0, ..FieldWeights::default() };
+        let mut engine = Bm25Engine::new().with_field_weights(heavy_theme_weights);
+        for r in &records {
+            engine.add_record(r);
+        }
+        let hits = engine.query_hits("zelenskyy", 10);
+        assert_eq!(hits[0].record_index, 1);
+    }
+}
@@ -0,0 +1,242 @@
+//! A BK-tree over the indexed vocabulary, used to expand a query term to
+//! near-misses ("Zelensky" -> "Zelenskyy") without scanning every term.
+
+/// The common "typo tolerance" ladder: shorter terms tolerate fewer edits,
+/// since a 2-edit match on a 2-letter term is nearly meaningless.
+pub fn max_edit_distance(term_len: usize) -> usize {
+    match term_len {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let temp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = temp;
+        }
+    }
+
+    row[b.len()]
+}
+
+struct Node {
+    term: String,
+    children: Vec<(usize, Node)>,
+}
+
+/// A BK-tree keyed on Levenshtein distance, giving sub-linear lookup of all
+/// vocabulary terms within a bounded edit distance of a query term.
+pub struct BkTree {
+    root: Option<Node>,
+}
+
+impl BkTree {
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    pub fn insert(&mut self, term: &str) {
+        match &mut self.root {
+            None => {
+                self.root = Some(Node {
+                    term: term.to_string(),
+                    children: Vec::new(),
+                })
+            }
+            Some(root) => Self::insert_node(root, term),
+        }
+    }
+
+    fn insert_node(node: &mut Node, term: &str) {
+        let dist = levenshtein(&node.term, term);
+        if dist == 0 {
+            return; // already present
+        }
+        match node.children.iter_mut().find(|(d, _)| *d == dist) {
+            Some((_, child)) => Self::insert_node(child, term),
+            None => node.children.push((
+                dist,
+                Node {
+                    term: term.to_string(),
+                    children: Vec::new(),
+                },
+            )),
+        }
+    }
+
+    /// Returns every indexed term within `max_distance` edits of `term`,
+    /// paired with the actual distance, so callers can weight exact hits
+    /// above 1-edit hits above 2-edit hits.
+    pub fn find_within(&self, term: &str, max_distance: usize) -> Vec<(String, usize)> {
+        let Some(root) = &self.root else {
+            return Vec::new();
+        };
+        let mut results = Vec::new();
+        Self::search_node(root, term, max_distance, &mut results);
+        results
+    }
+
+    fn search_node(node: &Node, term: &str, max_distance: usize, results: &mut Vec<(String, usize)>) {
+        let dist = levenshtein(&node.term, term);
+        if dist <= max_distance {
+            results.push((node.term.clone(), dist));
+        }
+        for (child_dist, child) in &node.children {
+            if child_dist.abs_diff(dist) <= max_distance {
+                Self::search_node(child, term, max_distance, results);
+            }
+        }
+    }
+}
+
+impl Default for BkTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Edit-distance budget for a single fuzzy-matched token, scaled by token
+/// length: short tokens demand an exact match (a 1-edit "fix" on a 3-letter
+/// word is nearly always a different word), while longer ones can absorb a
+/// transposition or two.
+pub fn token_match_budget(token_len: usize) -> u8 {
+    match token_len {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+/// Banded (diagonal) edit-distance check in which a swap of two adjacent
+/// letters counts as a single edit (optimal string alignment distance):
+/// fills only the cells within `budget` of the matrix diagonal and bails out
+/// as soon as an entire row exceeds the budget, so a clearly-too-different
+/// pair never pays for the full O(len_a * len_b) matrix.
+fn banded_levenshtein_within(a: &str, b: &str, budget: u8) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let budget = budget as usize;
+
+    if a.len().abs_diff(b.len()) > budget {
+        return false;
+    }
+
+    let unreachable = budget + 1;
+    let width = b.len() + 1;
+    let mut prev: Vec<usize> = (0..width).map(|j| if j <= budget { j } else { unreachable }).collect();
+    let mut prev2: Vec<usize> = vec![unreachable; width];
+
+    for i in 1..=a.len() {
+        let mut curr = vec![unreachable; width];
+        if i <= budget {
+            curr[0] = i;
+        }
+        let lo = i.saturating_sub(budget).max(1);
+        let hi = (i + budget).min(b.len());
+        let mut row_min = curr[0];
+
+        for j in lo..=hi {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let deletion = prev[j].saturating_add(1);
+            let insertion = curr[j - 1].saturating_add(1);
+            let substitution = prev[j - 1].saturating_add(cost);
+            curr[j] = deletion.min(insertion).min(substitution);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev2[j - 2].saturating_add(1));
+            }
+            row_min = row_min.min(curr[j]);
+        }
+
+        if row_min > budget {
+            return false;
+        }
+        prev2 = std::mem::replace(&mut prev, curr);
+    }
+
+    prev[b.len()] <= budget
+}
+
+/// Splits `haystack` into words and checks whether any of them is within
+/// `budget` edits of `needle`, the way a typo-tolerant search engine matches
+/// a query token against a multi-word field (e.g. a canonicalized theme)
+/// rather than requiring an exact substring hit.
+pub fn fuzzy_contains(haystack: &str, needle: &str, budget: u8) -> bool {
+    haystack.split_whitespace().any(|word| banded_levenshtein_within(word, needle, budget))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_edit_distance_follows_the_typo_ladder() {
+        assert_eq!(max_edit_distance(2), 0);
+        assert_eq!(max_edit_distance(5), 1);
+        assert_eq!(max_edit_distance(6), 2);
+    }
+
+    #[test]
+    fn finds_exact_and_near_matches() {
+        let mut tree = BkTree::new();
+        for term in ["zelensky", "zelenskyy", "trump", "biden"] {
+            tree.insert(term);
+        }
+        let mut hits = tree.find_within("zelensky", 1);
+        hits.sort();
+        assert_eq!(
+            hits,
+            vec![("zelensky".to_string(), 0), ("zelenskyy".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn excludes_terms_beyond_the_bound() {
+        let mut tree = BkTree::new();
+        tree.insert("trump");
+        assert!(tree.find_within("biden", 2).is_empty());
+    }
+
+    // ---- token_match_budget / fuzzy_contains ----
+
+    #[test]
+    fn token_match_budget_follows_the_length_ladder() {
+        assert_eq!(token_match_budget(4), 0);
+        assert_eq!(token_match_budget(8), 1);
+        assert_eq!(token_match_budget(9), 2);
+    }
+
+    #[test]
+    fn fuzzy_contains_matches_a_misspelled_word() {
+        let budget = token_match_budget("tamil".len());
+        assert!(fuzzy_contains("tax ethnicity tamil", "tamil", budget));
+        assert!(fuzzy_contains("tax ethnicity tamul", "tamil", budget));
+    }
+
+    #[test]
+    fn fuzzy_contains_respects_the_budget() {
+        let budget = token_match_budget("tamil".len());
+        assert!(!fuzzy_contains("tax ethnicity punjabi", "tamil", budget));
+    }
+
+    #[test]
+    fn fuzzy_contains_requires_exact_match_for_short_tokens() {
+        let budget = token_match_budget("tax".len());
+        assert_eq!(budget, 0);
+        assert!(!fuzzy_contains("tix ethnicity", "tax", budget));
+        assert!(fuzzy_contains("tax ethnicity", "tax", budget));
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use crate::model::GkgRecord;
+
+use super::ScoredHit;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Roughly how many km a degree of latitude spans; used only to build a
+/// cheap bounding box before the exact haversine check.
+const KM_PER_DEGREE_LAT: f64 = 111.0;
+
+/// Gathers `(record_index, latitude, longitude)` for every geocoded location
+/// across `records`' `v1_locations` and `v2_enhanced_locations`, skipping the
+/// `(0.0, 0.0)` default `LocationV1`/`EnhancedLocation` produce for
+/// unparsed/country-only entries — otherwise every unlocated record clusters
+/// off the coast of Africa.
+pub fn index_locations(records: &[GkgRecord]) -> Vec<(usize, f64, f64)> {
+    let mut locations = Vec::new();
+    for (idx, record) in records.iter().enumerate() {
+        for location in &record.v1_locations {
+            if location.latitude != 0.0 || location.longitude != 0.0 {
+                locations.push((idx, location.latitude, location.longitude));
+            }
+        }
+        for location in &record.v2_enhanced_locations {
+            if location.latitude != 0.0 || location.longitude != 0.0 {
+                locations.push((idx, location.latitude, location.longitude));
+            }
+        }
+    }
+    locations
+}
+
+/// Great-circle distance in kilometers between two lat/lon points via the
+/// haversine formula.
+fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let phi1 = lat1.to_radians();
+    let phi2 = lat2.to_radians();
+
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Cheap lat/lon bounding box around `(lat, lon)` at `radius_km`, dividing the
+/// longitude span by `cos(lat)` so it is narrowest at the equator and widens
+/// toward the poles, where a degree of longitude covers fewer km. Used to
+/// prune candidates before the exact haversine check.
+fn bounding_box(lat: f64, lon: f64, radius_km: f64) -> (f64, f64, f64, f64) {
+    let lat_span = radius_km / KM_PER_DEGREE_LAT;
+    let lon_span = radius_km / (KM_PER_DEGREE_LAT * lat.to_radians().cos().max(f64::EPSILON));
+    (lat - lat_span, lat + lat_span, lon - lon_span, lon + lon_span)
+}
+
+/// Keeps only the highest-scoring [`ScoredHit`] per `record_index`, so a
+/// record with two or more qualifying geocoded locations contributes a
+/// single hit (its nearest/best-scoring location) instead of one per match.
+fn dedup_best_per_record(hits: Vec<ScoredHit>) -> Vec<ScoredHit> {
+    let mut best: HashMap<usize, ScoredHit> = HashMap::new();
+    for hit in hits {
+        best.entry(hit.record_index)
+            .and_modify(|existing| {
+                if hit.score > existing.score {
+                    *existing = hit;
+                }
+            })
+            .or_insert(hit);
+    }
+    best.into_values().collect()
+}
+
+/// Finds every indexed location within `radius_km` of `(lat, lon)`, first
+/// pruning with [`bounding_box`] and then scoring survivors by exact
+/// haversine distance so nearer records rank higher (`score = 1/(1+d)`). A
+/// record with multiple qualifying locations contributes only its nearest
+/// one (see [`dedup_best_per_record`]). Returns the top `limit` hits sorted
+/// by descending score.
+pub fn search_radius(
+    locations: &[(usize, f64, f64)],
+    lat: f64,
+    lon: f64,
+    radius_km: f64,
+    limit: usize,
+) -> Vec<ScoredHit> {
+    let (min_lat, max_lat, min_lon, max_lon) = bounding_box(lat, lon, radius_km);
+
+    let hits: Vec<ScoredHit> = locations
+        .iter()
+        .filter(|(_, loc_lat, loc_lon)| {
+            (*loc_lat >= min_lat && *loc_lat <= max_lat) && (*loc_lon >= min_lon && *loc_lon <= max_lon)
+        })
+        .filter_map(|(idx, loc_lat, loc_lon)| {
+            let distance = haversine_km(lat, lon, *loc_lat, *loc_lon);
+            if distance <= radius_km {
+                Some(ScoredHit { record_index: *idx, score: (1.0 / (1.0 + distance)) as f32 })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let mut hits = dedup_best_per_record(hits);
+    hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    hits.truncate(limit);
+    hits
+}
+
+/// Finds every indexed location within the `[min_lat, max_lat] x [min_lon,
+/// max_lon]` bounding box, scored the same way as [`search_radius`] by
+/// distance from the box's center so nearer records still rank higher. A
+/// record with multiple qualifying locations contributes only its
+/// best-scoring one (see [`dedup_best_per_record`]). Returns the top `limit`
+/// hits sorted by descending score.
+pub fn search_bbox(
+    locations: &[(usize, f64, f64)],
+    min_lat: f64,
+    max_lat: f64,
+    min_lon: f64,
+    max_lon: f64,
+    limit: usize,
+) -> Vec<ScoredHit> {
+    let center_lat = (min_lat + max_lat) / 2.0;
+    let center_lon = (min_lon + max_lon) / 2.0;
+
+    let hits: Vec<ScoredHit> = locations
+        .iter()
+        .filter(|(_, loc_lat, loc_lon)| {
+            (*loc_lat >= min_lat && *loc_lat <= max_lat) && (*loc_lon >= min_lon && *loc_lon <= max_lon)
+        })
+        .map(|(idx, loc_lat, loc_lon)| {
+            let distance = haversine_km(center_lat, center_lon, *loc_lat, *loc_lon);
+            ScoredHit { record_index: *idx, score: (1.0 / (1.0 + distance)) as f32 }
+        })
+        .collect();
+
+    let mut hits = dedup_best_per_record(hits);
+    hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    hits.truncate(limit);
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_record_with_location(id: &str, lat: f64, lon: f64) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: id.into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "example.com".into(),
+            document_identifier: format!("https://example.com/{id}"),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec![],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![LocationV1 {
+                location_type: 4,
+                full_name: "Somewhere".into(),
+                country_code: "US".into(),
+                adm1_code: "US06".into(),
+                latitude: lat,
+                longitude: lon,
+                feature_id: "X".into(),
+            }],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec![],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn index_locations_skips_zero_zero_default_coordinates() {
+        let records = vec![make_record_with_location("1", 0.0, 0.0), make_record_with_location("2", 38.0, -97.0)];
+        let locations = index_locations(&records);
+        assert_eq!(locations.len(), 1);
+        assert_eq!(locations[0].0, 1);
+    }
+
+    #[test]
+    fn haversine_km_returns_zero_for_identical_points() {
+        assert_eq!(haversine_km(38.0, -97.0, 38.0, -97.0), 0.0);
+    }
+
+    #[test]
+    fn haversine_km_matches_known_distance_between_nyc_and_la() {
+        // New York City to Los Angeles is ~3936 km.
+        let distance = haversine_km(40.7128, -74.0060, 34.0522, -118.2437);
+        assert!((distance - 3936.0).abs() < 20.0);
+    }
+
+    #[test]
+    fn search_radius_finds_nearby_and_excludes_far_records() {
+        let records =
+            vec![make_record_with_location("near", 38.1, -97.1), make_record_with_location("far", 51.5, -0.1)];
+        let locations = index_locations(&records);
+        let hits = search_radius(&locations, 38.0, -97.0, 50.0, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].record_index, 0);
+    }
+
+    #[test]
+    fn search_radius_ranks_nearer_records_higher() {
+        let records = vec![
+            make_record_with_location("far", 39.0, -97.0),
+            make_record_with_location("near", 38.01, -97.0),
+        ];
+        let locations = index_locations(&records);
+        let hits = search_radius(&locations, 38.0, -97.0, 500.0, 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].record_index, 1);
+    }
+
+    #[test]
+    fn search_bbox_finds_records_inside_the_box_only() {
+        let records =
+            vec![make_record_with_location("inside", 38.0, -97.0), make_record_with_location("outside", 60.0, 10.0)];
+        let locations = index_locations(&records);
+        let hits = search_bbox(&locations, 30.0, 45.0, -105.0, -90.0, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].record_index, 0);
+    }
+
+    #[test]
+    fn search_radius_returns_one_hit_for_a_record_with_multiple_qualifying_locations() {
+        let mut record = make_record_with_location("multi", 38.0, -97.0);
+        record.v2_enhanced_locations.push(EnhancedLocation {
+            location_type: 4,
+            full_name: "Somewhere Else".into(),
+            country_code: "US".into(),
+            adm1_code: "US06".into(),
+            adm2_code: "".into(),
+            latitude: 38.05,
+            longitude: -97.05,
+            feature_id: "Y".into(),
+            char_offset: 0,
+        });
+        let locations = index_locations(std::slice::from_ref(&record));
+        assert_eq!(locations.len(), 2);
+
+        let hits = search_radius(&locations, 38.0, -97.0, 50.0, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].record_index, 0);
+    }
+
+    #[test]
+    fn search_radius_respects_the_limit() {
+        let records = vec![
+            make_record_with_location("1", 38.0, -97.0),
+            make_record_with_location("2", 38.01, -97.0),
+            make_record_with_location("3", 38.02, -97.0),
+        ];
+        let locations = index_locations(&records);
+        let hits = search_radius(&locations, 38.0, -97.0, 500.0, 2);
+        assert_eq!(hits.len(), 2);
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use crate::error::NewsfreshError;
+use crate::model::GkgRecord;
+
+use super::tokenize::tokenize;
+use super::ScoredHit;
+
+/// A pure-Rust in-memory [`super::SearchEngine`] with no external index
+/// dependency: an inverted index over `themes`/`persons`/`organizations`/
+/// `names` scored by TF-IDF. Lighter-weight than [`super::tantivy::TantivyEngine`]
+/// or [`super::bm25::Bm25Engine`] — no Tantivy/zstd tree, no BM25 length
+/// normalization — for WASM builds or other minimal-footprint targets where
+/// the `tantivy` feature is disabled.
+pub struct InvertedIndexEngine {
+    /// term -> Vec<(record_index, term_frequency)>
+    postings: HashMap<String, Vec<(usize, u32)>>,
+    doc_lengths: Vec<u32>,
+    num_docs: usize,
+}
+
+impl InvertedIndexEngine {
+    pub fn new() -> Self {
+        Self {
+            postings: HashMap::new(),
+            doc_lengths: Vec::new(),
+            num_docs: 0,
+        }
+    }
+
+    fn document_text(record: &GkgRecord) -> String {
+        let mut parts: Vec<&str> = record.v1_themes.iter().map(|s| s.as_str()).collect();
+        parts.extend(record.v2_enhanced_themes.iter().map(|t| t.theme.as_str()));
+        parts.extend(record.v1_persons.iter().map(|s| s.as_str()));
+        parts.extend(record.v2_enhanced_persons.iter().map(|p| p.name.as_str()));
+        parts.extend(record.v1_organizations.iter().map(|s| s.as_str()));
+        parts.extend(record.v2_enhanced_organizations.iter().map(|o| o.name.as_str()));
+        parts.extend(record.all_names.iter().map(|n| n.name.as_str()));
+        parts.join(" ")
+    }
+
+    fn idf(&self, term: &str) -> f32 {
+        let df = self.postings.get(term).map(|p| p.len()).unwrap_or(0) as f32;
+        if df == 0.0 {
+            return 0.0;
+        }
+        let n = self.num_docs as f32;
+        (n / df).ln() + 1.0
+    }
+
+    /// Scores every record containing at least one query term by summing
+    /// `tf * idf` per matched term, and returns the top `limit` hits sorted
+    /// by descending score.
+    pub fn query_hits(&self, query_str: &str, limit: usize) -> Vec<ScoredHit> {
+        let terms = tokenize(query_str);
+        if terms.is_empty() || self.num_docs == 0 {
+            return Vec::new();
+        }
+
+        let mut scores: HashMap<usize, f32> = HashMap::new();
+        for term in &terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let idf = self.idf(term);
+            for &(doc_idx, tf) in postings {
+                *scores.entry(doc_idx).or_insert(0.0) += tf as f32 * idf;
+            }
+        }
+
+        let mut hits: Vec<ScoredHit> = scores
+            .into_iter()
+            .map(|(record_index, score)| ScoredHit { record_index, score })
+            .collect();
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+impl Default for InvertedIndexEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::SearchEngine for InvertedIndexEngine {
+    fn build(&mut self, records: &[GkgRecord]) -> Result<(), NewsfreshError> {
+        *self = Self::new();
+        for record in records {
+            let text = Self::document_text(record);
+            let tokens = tokenize(&text);
+            let idx = self.doc_lengths.len();
+
+            let mut term_freqs: HashMap<String, u32> = HashMap::new();
+            for token in &tokens {
+                *term_freqs.entry(token.clone()).or_insert(0) += 1;
+            }
+            for (term, freq) in term_freqs {
+                self.postings.entry(term).or_default().push((idx, freq));
+            }
+
+            self.doc_lengths.push(tokens.len() as u32);
+            self.num_docs += 1;
+        }
+        Ok(())
+    }
+
+    fn search(&self, query_str: &str, limit: usize) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        Ok(self.query_hits(query_str, limit))
+    }
+
+    fn search_geo(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_km: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        let _ = (lat, lon, radius_km, limit);
+        Err(NewsfreshError::Other(
+            "InvertedIndexEngine does not index location coordinates".into(),
+        ))
+    }
+
+    fn search_geo_bbox(
+        &self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        let _ = (min_lat, max_lat, min_lon, max_lon, limit);
+        Err(NewsfreshError::Other(
+            "InvertedIndexEngine does not index location coordinates".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+    use crate::search::SearchEngine;
+
+    fn make_record(id: &str, persons: Vec<&str>, themes: Vec<&str>) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: id.into(),
+            date: 20250217120000,
+            source_collection_id: 1,
+            source_common_name: "example.com".into(),
+            document_identifier: format!("https://example.com/{id}"),
+            v1_persons: persons.into_iter().map(String::from).collect(),
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            v1_themes: themes.into_iter().map(String::from).collect(),
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            tone: None,
+            quotations: vec![],
+            sharing_image: None,
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn ranks_rarer_term_matches_higher() {
+        let records = vec![
+            make_record("1", vec!["donald trump"], vec!["ELECTION"]),
+            make_record("2", vec!["donald trump"], vec!["TAX_POLICY"]),
+            make_record("3", vec!["elon musk"], vec!["TAX_POLICY"]),
+        ];
+        let mut engine = InvertedIndexEngine::new();
+        engine.build(&records).unwrap();
+        let hits = engine.query_hits("election", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].record_index, 0);
+    }
+
+    #[test]
+    fn empty_query_returns_no_hits() {
+        let engine = InvertedIndexEngine::new();
+        assert!(engine.query_hits("", 10).is_empty());
+    }
+
+    #[test]
+    fn search_trait_method_delegates_to_query_hits() {
+        let records = vec![make_record("1", vec!["donald trump"], vec![])];
+        let mut engine = InvertedIndexEngine::new();
+        engine.build(&records).unwrap();
+        let hits = engine.search("trump", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[test]
+    fn search_geo_is_unsupported() {
+        let engine = InvertedIndexEngine::new();
+        assert!(engine.search_geo(0.0, 0.0, 10.0, 5).is_err());
+        assert!(engine.search_geo_bbox(0.0, 1.0, 0.0, 1.0, 5).is_err());
+    }
+
+    #[test]
+    fn rebuilding_the_engine_replaces_the_previous_index() {
+        let mut engine = InvertedIndexEngine::new();
+        engine.build(&[make_record("1", vec!["donald trump"], vec![])]).unwrap();
+        engine.build(&[make_record("2", vec!["elon musk"], vec![])]).unwrap();
+        assert!(engine.query_hits("trump", 10).is_empty());
+        assert!(!engine.query_hits("musk", 10).is_empty());
+    }
+}
@@ -1,8 +1,15 @@
 mod adm1;
-mod enrich;
+pub mod bm25;
+pub(crate) mod enrich;
 pub(crate) mod fips;
+pub mod fuzzy;
+mod geo;
+mod inverted;
+pub mod snippet;
+#[cfg(feature = "tantivy")]
 mod tantivy;
-mod themes;
+pub(crate) mod themes;
+mod tokenize;
 
 use crate::error::NewsfreshError;
 use crate::model::GkgRecord;
@@ -15,8 +22,73 @@ pub struct ScoredHit {
 pub trait SearchEngine {
     fn build(&mut self, records: &[GkgRecord]) -> Result<(), NewsfreshError>;
     fn search(&self, query_str: &str, limit: usize) -> Result<Vec<ScoredHit>, NewsfreshError>;
+
+    /// Finds records geocoded within `radius_km` of `(lat, lon)`, nearest
+    /// first (see [`geo::search_radius`]).
+    fn search_geo(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_km: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError>;
+
+    /// Finds records geocoded within the `[min_lat, max_lat] x [min_lon,
+    /// max_lon]` bounding box, nearest-to-center first (see
+    /// [`geo::search_bbox`]).
+    fn search_geo_bbox(
+        &self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError>;
+}
+
+/// Builds a search engine for the named `backend`:
+///
+/// - `"tantivy"` — the default (only available with the `tantivy` feature on),
+///   backed by a full Tantivy index with BM25 ranking
+/// - `"bm25"` — the pure-Rust [`bm25::Bm25Engine`], same as [`create_bm25_engine`]
+/// - `"inverted"` — the pure-Rust [`inverted::InvertedIndexEngine`], a
+///   lightweight TF-IDF inverted index with no external dependency — the
+///   fallback default (and only option) when the `tantivy` feature is off,
+///   for WASM builds or other minimal-footprint targets
+///
+/// Any other name (including `""`) falls back to the default for whichever
+/// features are enabled.
+pub fn create_engine(backend: &str) -> Box<dyn SearchEngine> {
+    match backend {
+        "bm25" => Box::new(bm25::Bm25Engine::new()),
+        "inverted" => Box::new(inverted::InvertedIndexEngine::new()),
+        #[cfg(feature = "tantivy")]
+        _ => Box::new(tantivy::TantivyEngine::new()),
+        #[cfg(not(feature = "tantivy"))]
+        _ => Box::new(inverted::InvertedIndexEngine::new()),
+    }
+}
+
+/// Builds the pure-Rust BM25 engine instead of the default Tantivy-backed one.
+///
+/// Useful when an explicit, tunable BM25 formula matters more than Tantivy's
+/// query language, or in environments where pulling in Tantivy is undesirable.
+pub fn create_bm25_engine() -> Box<dyn SearchEngine> {
+    Box::new(bm25::Bm25Engine::new())
 }
 
-pub fn create_engine() -> Box<dyn SearchEngine> {
-    Box::new(tantivy::TantivyEngine::new())
+/// Like [`create_bm25_engine`], but with explicit `k1`/`b` tuning knobs, a
+/// typo-tolerance toggle, and per-field term-frequency weights (see
+/// `--bm25-k1`/`--bm25-b`/`--typo-tolerance`/`--bm25-field-weights`).
+pub fn create_bm25_engine_with_params(
+    k1: f32,
+    b: f32,
+    typo_tolerance: bool,
+    field_weights: bm25::FieldWeights,
+) -> Box<dyn SearchEngine> {
+    Box::new(
+        bm25::Bm25Engine::with_params(k1, b)
+            .with_typo_tolerance(typo_tolerance)
+            .with_field_weights(field_weights),
+    )
 }
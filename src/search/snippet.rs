@@ -0,0 +1,184 @@
+use crate::model::GkgRecord;
+
+use super::tokenize::tokenize;
+
+/// Tuning knobs for [`generate_snippets`].
+pub struct SnippetConfig {
+    /// Size of the sliding window, in whitespace-delimited words.
+    pub window_size: usize,
+    /// Maximum number of snippets to return per record.
+    pub max_snippets: usize,
+    /// Marker inserted before a matched term.
+    pub mark_open: String,
+    /// Marker inserted after a matched term.
+    pub mark_close: String,
+}
+
+impl Default for SnippetConfig {
+    fn default() -> Self {
+        Self {
+            window_size: 30,
+            max_snippets: 1,
+            mark_open: "<em>".to_string(),
+            mark_close: "</em>".to_string(),
+        }
+    }
+}
+
+/// Candidate text to draw snippets from: quotations first, `extras_xml` as a
+/// fallback when a record has no quotations.
+fn candidate_text(record: &GkgRecord) -> String {
+    if !record.quotations.is_empty() {
+        record
+            .quotations
+            .iter()
+            .map(|q| q.quote.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        record.extras_xml.clone().unwrap_or_default()
+    }
+}
+
+/// Generates highlighted search-result snippets for `record` given `query`.
+///
+/// Splits the candidate text into overlapping windows of `config.window_size`
+/// words, scores each window by how many distinct query terms it contains,
+/// and returns the highest-density windows (in source order) with matched
+/// terms wrapped in `config.mark_open`/`config.mark_close`.
+pub fn generate_snippets(query: &str, record: &GkgRecord, config: &SnippetConfig) -> Vec<String> {
+    let query_terms: std::collections::HashSet<String> = tokenize(query).into_iter().collect();
+    if query_terms.is_empty() {
+        return Vec::new();
+    }
+
+    let text = candidate_text(record);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let step = (config.window_size / 2).max(1);
+    let mut windows: Vec<(usize, usize, usize)> = Vec::new(); // (start, end, score)
+
+    let mut start = 0;
+    while start < words.len() {
+        let end = (start + config.window_size).min(words.len());
+        let mut matched = std::collections::HashSet::new();
+        for word in &words[start..end] {
+            for token in tokenize(word) {
+                if query_terms.contains(&token) {
+                    matched.insert(token);
+                }
+            }
+        }
+        if !matched.is_empty() {
+            windows.push((start, end, matched.len()));
+        }
+        if end == words.len() {
+            break;
+        }
+        start += step;
+    }
+
+    windows.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+    windows.truncate(config.max_snippets);
+    windows.sort_by_key(|w| w.0);
+
+    windows
+        .into_iter()
+        .map(|(start, end, _)| highlight_window(&words[start..end], &query_terms, config))
+        .collect()
+}
+
+fn highlight_window(
+    words: &[&str],
+    query_terms: &std::collections::HashSet<String>,
+    config: &SnippetConfig,
+) -> String {
+    words
+        .iter()
+        .map(|word| {
+            let is_match = tokenize(word).iter().any(|t| query_terms.contains(t));
+            if is_match {
+                format!("{}{word}{}", config.mark_open, config.mark_close)
+            } else {
+                word.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+
+    fn make_record(quote: &str) -> GkgRecord {
+        GkgRecord {
+            gkg_record_id: "1".into(),
+            date: 0,
+            source_collection_id: 0,
+            source_common_name: String::new(),
+            document_identifier: String::new(),
+            v1_counts: vec![],
+            v21_counts: vec![],
+            v1_themes: vec![],
+            v2_enhanced_themes: vec![],
+            v1_locations: vec![],
+            v2_enhanced_locations: vec![],
+            v1_persons: vec![],
+            v2_enhanced_persons: vec![],
+            v1_organizations: vec![],
+            v2_enhanced_organizations: vec![],
+            tone: None,
+            v21_enhanced_dates: vec![],
+            gcam: vec![],
+            sharing_image: None,
+            related_images: vec![],
+            social_image_embeds: vec![],
+            social_video_embeds: vec![],
+            quotations: vec![Quotation {
+                offset: 0,
+                length: quote.len() as i64,
+                verb: "said".into(),
+                quote: quote.to_string(),
+            }],
+            all_names: vec![],
+            amounts: vec![],
+            translation_info: None,
+            extras_xml: None,
+        }
+    }
+
+    #[test]
+    fn highlights_matched_terms() {
+        let record = make_record("the new tariffs on steel imports will raise prices");
+        let config = SnippetConfig {
+            window_size: 10,
+            ..SnippetConfig::default()
+        };
+        let snippets = generate_snippets("tariffs steel", &record, &config);
+        assert_eq!(snippets.len(), 1);
+        assert!(snippets[0].contains("<em>tariffs</em>"));
+        assert!(snippets[0].contains("<em>steel</em>"));
+    }
+
+    #[test]
+    fn no_match_returns_empty() {
+        let record = make_record("completely unrelated text about weather");
+        let snippets = generate_snippets("tariffs", &record, &SnippetConfig::default());
+        assert!(snippets.is_empty());
+    }
+
+    #[test]
+    fn falls_back_to_extras_xml_when_no_quotations() {
+        let mut record = make_record("");
+        record.quotations = vec![];
+        record.extras_xml = Some("tariffs on steel imports".to_string());
+        let snippets = generate_snippets("tariffs", &record, &SnippetConfig::default());
+        assert_eq!(snippets.len(), 1);
+        assert!(snippets[0].contains("<em>tariffs</em>"));
+    }
+}
@@ -1,14 +1,60 @@
 use tantivy::collector::TopDocs;
 use tantivy::query::QueryParser;
 use tantivy::schema::*;
+use tantivy::tokenizer::{Language as TantivyLanguage, LowerCaser, SimpleTokenizer, Stemmer, TextAnalyzer};
 use tantivy::{doc, Index, ReloadPolicy};
 
 use crate::error::NewsfreshError;
 use crate::model::GkgRecord;
+use crate::nlp::{self, Language};
 
 use super::enrich;
+use super::geo;
 use super::ScoredHit;
 
+/// Every [`Language`] this engine routes documents to a dedicated tokenizer
+/// for, in a fixed order used to build per-language schema fields.
+const LANGUAGES: [Language; 5] = [
+    Language::English,
+    Language::Spanish,
+    Language::French,
+    Language::German,
+    Language::Portuguese,
+];
+
+fn tantivy_language(lang: Language) -> TantivyLanguage {
+    match lang {
+        Language::English => TantivyLanguage::English,
+        Language::Spanish => TantivyLanguage::Spanish,
+        Language::French => TantivyLanguage::French,
+        Language::German => TantivyLanguage::German,
+        Language::Portuguese => TantivyLanguage::Portuguese,
+    }
+}
+
+/// The tokenizer name registered on the index for `lang` (e.g. `"lang_es"`),
+/// stemming and lower-casing text with that language's rules.
+fn tokenizer_name(lang: Language) -> &'static str {
+    match lang {
+        Language::English => "lang_en",
+        Language::Spanish => "lang_es",
+        Language::French => "lang_fr",
+        Language::German => "lang_de",
+        Language::Portuguese => "lang_pt",
+    }
+}
+
+/// The schema field name for `lang`'s per-language content (e.g. `"content_es"`).
+fn content_field_name(lang: Language) -> &'static str {
+    match lang {
+        Language::English => "content_en",
+        Language::Spanish => "content_es",
+        Language::French => "content_fr",
+        Language::German => "content_de",
+        Language::Portuguese => "content_pt",
+    }
+}
+
 struct GkgSearchSchema {
     schema: Schema,
     record_idx: Field,
@@ -20,6 +66,10 @@ struct GkgSearchSchema {
     names: Field,
     source: Field,
     document_id: Field,
+    /// One field per [`LANGUAGES`] entry, each indexed with that language's
+    /// own stemmer/tokenizer (see [`tokenizer_name`]) so a document's full
+    /// text is searchable without being mangled by English-only stemming.
+    content_fields: Vec<(Language, Field)>,
 }
 
 impl GkgSearchSchema {
@@ -34,6 +84,18 @@ impl GkgSearchSchema {
         let names = builder.add_text_field("names", TEXT);
         let source = builder.add_text_field("source", TEXT);
         let document_id = builder.add_text_field("document_id", TEXT);
+
+        let content_fields = LANGUAGES
+            .iter()
+            .map(|&lang| {
+                let indexing = TextFieldIndexing::default()
+                    .set_tokenizer(tokenizer_name(lang))
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions);
+                let options = TextOptions::default().set_indexing_options(indexing);
+                (lang, builder.add_text_field(content_field_name(lang), options))
+            })
+            .collect();
+
         let schema = builder.build();
 
         Self {
@@ -47,6 +109,7 @@ impl GkgSearchSchema {
             names,
             source,
             document_id,
+            content_fields,
         }
     }
 
@@ -62,11 +125,46 @@ impl GkgSearchSchema {
             self.document_id,
         ]
     }
+
+    fn content_field(&self, lang: Language) -> Field {
+        self.content_fields
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .map(|(_, field)| *field)
+            .expect("content_fields covers every LANGUAGES entry")
+    }
+}
+
+/// Registers a stemming `TextAnalyzer` for every [`LANGUAGES`] entry on
+/// `index`, so each per-language content field's configured tokenizer name
+/// (see [`tokenizer_name`]) resolves to an analyzer that lower-cases and
+/// stems in that language rather than the index default.
+fn register_language_tokenizers(index: &Index) {
+    for &lang in &LANGUAGES {
+        let analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(LowerCaser)
+            .filter(Stemmer::new(tantivy_language(lang)))
+            .build();
+        index.tokenizers().register(tokenizer_name(lang), analyzer);
+    }
+}
+
+/// Determines which language to index `record`'s full text under: the
+/// language declared in `translation_info` when present, otherwise a
+/// best-effort guess from the record's own enriched text — the same
+/// fallback [`super::bm25::Bm25Engine`] uses.
+fn record_language(record: &GkgRecord, text: &str) -> Language {
+    record
+        .translation_info
+        .as_ref()
+        .map(|t| Language::from_code(&t.source_language))
+        .unwrap_or_else(|| nlp::detect(text))
 }
 
 pub struct TantivyEngine {
     search_schema: GkgSearchSchema,
     index: Option<Index>,
+    locations: Vec<(usize, f64, f64)>,
 }
 
 impl TantivyEngine {
@@ -74,43 +172,22 @@ impl TantivyEngine {
         Self {
             search_schema: GkgSearchSchema::new(),
             index: None,
+            locations: Vec::new(),
         }
     }
-}
 
-impl super::SearchEngine for TantivyEngine {
-    fn build(&mut self, records: &[GkgRecord]) -> Result<(), NewsfreshError> {
-        let index = Index::create_in_ram(self.search_schema.schema.clone());
-        let mut writer = index
-            .writer_with_num_threads(1, 50_000_000)
-            .map_err(|e| NewsfreshError::Other(format!("Failed to create index writer: {e}")))?;
-
-        for (idx, record) in records.iter().enumerate() {
-            let enriched = enrich::enrich_record(record);
-            writer
-                .add_document(doc!(
-                    self.search_schema.record_idx => idx as u64,
-                    self.search_schema.persons => enriched.persons,
-                    self.search_schema.organizations => enriched.organizations,
-                    self.search_schema.themes => enriched.themes,
-                    self.search_schema.locations => enriched.locations,
-                    self.search_schema.quotations => enriched.quotations,
-                    self.search_schema.names => enriched.names,
-                    self.search_schema.source => enriched.source,
-                    self.search_schema.document_id => enriched.document_id,
-                ))
-                .map_err(|e| NewsfreshError::Other(format!("Failed to add document: {e}")))?;
-        }
-
-        writer
-            .commit()
-            .map_err(|e| NewsfreshError::Other(format!("Failed to commit index: {e}")))?;
-
-        self.index = Some(index);
-        Ok(())
-    }
-
-    fn search(&self, query_str: &str, limit: usize) -> Result<Vec<ScoredHit>, NewsfreshError> {
+    /// Like [`super::SearchEngine::search`], but lets the caller pin the
+    /// query's analysis to a single language's tokenizer/stemmer instead of
+    /// matching against every indexed language. Passing `None` falls back to
+    /// the default behavior of searching across every per-language content
+    /// field (plus the existing structured fields), each analyzed with its own
+    /// field's tokenizer.
+    pub fn search_with_language(
+        &self,
+        query_str: &str,
+        language: Option<Language>,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
         let index = self
             .index
             .as_ref()
@@ -126,8 +203,16 @@ impl super::SearchEngine for TantivyEngine {
 
         let searcher = reader.searcher();
 
-        let query_parser =
-            QueryParser::for_index(index, self.search_schema.all_text_fields());
+        let fields = match language {
+            Some(lang) => vec![self.search_schema.content_field(lang)],
+            None => {
+                let mut fields = self.search_schema.all_text_fields();
+                fields.extend(self.search_schema.content_fields.iter().map(|(_, f)| *f));
+                fields
+            }
+        };
+
+        let query_parser = QueryParser::for_index(index, fields);
         let query = query_parser
             .parse_query(query_str)
             .map_err(|e| NewsfreshError::Other(format!("Failed to parse query: {e}")))?;
@@ -155,3 +240,78 @@ impl super::SearchEngine for TantivyEngine {
         Ok(hits)
     }
 }
+
+impl super::SearchEngine for TantivyEngine {
+    fn build(&mut self, records: &[GkgRecord]) -> Result<(), NewsfreshError> {
+        let index = Index::create_in_ram(self.search_schema.schema.clone());
+        register_language_tokenizers(&index);
+
+        let mut writer = index
+            .writer_with_num_threads(1, 50_000_000)
+            .map_err(|e| NewsfreshError::Other(format!("Failed to create index writer: {e}")))?;
+
+        for (idx, record) in records.iter().enumerate() {
+            let enriched = enrich::enrich_record(record);
+            let content_text = [
+                enriched.persons.as_str(),
+                enriched.organizations.as_str(),
+                enriched.themes.as_str(),
+                enriched.locations.as_str(),
+                enriched.quotations.as_str(),
+                enriched.names.as_str(),
+                enriched.source.as_str(),
+            ]
+            .join(" ");
+            let lang = record_language(record, &content_text);
+            let content_field = self.search_schema.content_field(lang);
+
+            writer
+                .add_document(doc!(
+                    self.search_schema.record_idx => idx as u64,
+                    self.search_schema.persons => enriched.persons,
+                    self.search_schema.organizations => enriched.organizations,
+                    self.search_schema.themes => enriched.themes,
+                    self.search_schema.locations => enriched.locations,
+                    self.search_schema.quotations => enriched.quotations,
+                    self.search_schema.names => enriched.names,
+                    self.search_schema.source => enriched.source,
+                    self.search_schema.document_id => enriched.document_id,
+                    content_field => content_text,
+                ))
+                .map_err(|e| NewsfreshError::Other(format!("Failed to add document: {e}")))?;
+        }
+
+        writer
+            .commit()
+            .map_err(|e| NewsfreshError::Other(format!("Failed to commit index: {e}")))?;
+
+        self.index = Some(index);
+        self.locations = geo::index_locations(records);
+        Ok(())
+    }
+
+    fn search(&self, query_str: &str, limit: usize) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        self.search_with_language(query_str, None, limit)
+    }
+
+    fn search_geo(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_km: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        Ok(geo::search_radius(&self.locations, lat, lon, radius_km, limit))
+    }
+
+    fn search_geo_bbox(
+        &self,
+        min_lat: f64,
+        max_lat: f64,
+        min_lon: f64,
+        max_lon: f64,
+        limit: usize,
+    ) -> Result<Vec<ScoredHit>, NewsfreshError> {
+        Ok(geo::search_bbox(&self.locations, min_lat, max_lat, min_lon, max_lon, limit))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::nlp::{self, Language};
+
+/// Tokenizes free text into lowercase, stemmed, stopword-filtered terms,
+/// using the English stopword/stemmer pair.
+///
+/// Splits on any non-alphanumeric character, so punctuation and GKG's
+/// `;`/`#`/`,` delimiters all act as separators.
+pub fn tokenize(text: &str) -> Vec<String> {
+    tokenize_lang(text, Language::English)
+}
+
+/// Like [`tokenize`], but applies the stopword list and stemmer for `lang`
+/// instead of always assuming English.
+pub fn tokenize_lang(text: &str, lang: Language) -> Vec<String> {
+    let stopwords = nlp::stopwords(lang);
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|w| w.to_lowercase())
+        .filter(|w| !w.is_empty() && !stopwords.contains(w.as_str()))
+        .map(|w| nlp::stem(&w, lang))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_punctuation() {
+        let tokens = tokenize("Climate-change, tariffs; and trade.");
+        assert!(tokens.iter().any(|t| t.starts_with("climat")));
+        assert!(!tokens.iter().any(|t| t == "and"));
+    }
+
+    #[test]
+    fn drops_stopwords() {
+        let tokens = tokenize("the quick fox is in the house");
+        assert!(!tokens.contains(&"the".to_string()));
+        assert!(!tokens.contains(&"is".to_string()));
+        assert!(!tokens.contains(&"in".to_string()));
+    }
+
+    #[test]
+    fn empty_input_yields_no_tokens() {
+        assert!(tokenize("").is_empty());
+        assert!(tokenize("   ").is_empty());
+    }
+
+    #[test]
+    fn folds_plurals_together() {
+        assert_eq!(tokenize("tariffs"), tokenize("tariff"));
+    }
+
+    #[test]
+    fn tokenize_lang_uses_language_specific_stopwords() {
+        let tokens = tokenize_lang("el presidente dijo", Language::Spanish);
+        assert!(!tokens.contains(&"el".to_string()));
+    }
+}